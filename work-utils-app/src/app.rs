@@ -3,10 +3,12 @@ use crate::converter::ConverterApp;
 #[cfg(all(feature = "web_app", feature = "offline"))]
 compile_error!("feature \"web_app\" and feature \"offline\" cannot be enabled at the same time. 'offline' is the default- you may want to disable default features to enable 'web_app'.");
 
-#[derive(Default)]
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
 pub struct State {
     data_converter: ConverterApp,
 
+    #[serde(skip)]
     selected_anchor: Anchor,
 }
 
@@ -15,11 +17,19 @@ pub struct MainApp {
 }
 
 impl MainApp {
-    pub fn new(_: &eframe::CreationContext<'_>) -> Self {
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         #[allow(unused_mut)]
         let mut slf = Self {
             state: State::default(),
         };
+
+        #[cfg(feature = "persistence")]
+        if let Some(storage) = cc.storage {
+            if let Some(state) = eframe::get_value(storage, eframe::APP_KEY) {
+                slf.state = state;
+            }
+        }
+
         slf
     }
 
@@ -43,6 +53,11 @@ impl MainApp {
 }
 
 impl eframe::App for MainApp {
+    #[cfg(feature = "persistence")]
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, eframe::APP_KEY, &self.state);
+    }
+
     fn clear_color(&self, visuals: &egui::Visuals) -> [f32; 4] {
         // Give the area behind the floating windows a different color, because it looks better:
         let color = egui::lerp(
@@ -0,0 +1,239 @@
+use egui::*;
+use serde::{Deserialize, Serialize};
+
+use crate::files::save_file;
+use crate::{VYBE_STAKE_VALIDATOR, VYBE_TOKEN_ACCOUNT, WSOL_ACCOUNT, WYATT_TEST_ACCOUNT};
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AddressEntry {
+    pub address: String,
+    pub label: String,
+    /// Comma separated
+    pub tags: String,
+    pub notes: String,
+}
+
+impl AddressEntry {
+    fn new(address: &str, label: &str, tags: &str) -> Self {
+        Self {
+            address: address.to_string(),
+            label: label.to_string(),
+            tags: tags.to_string(),
+            notes: "".to_string(),
+        }
+    }
+
+    fn tags(&self) -> impl Iterator<Item = &str> {
+        self.tags
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+    }
+
+    /// Case-insensitive search over every field
+    fn matches(&self, search: &str) -> bool {
+        let search = search.trim().to_lowercase();
+        [&self.address, &self.label, &self.tags, &self.notes]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&search))
+    }
+}
+
+/// Addresses with labels, tags and notes, and the file name to save them as
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct AddressBook {
+    pub entries: Vec<AddressEntry>,
+    pub file_name: String,
+
+    #[serde(skip)]
+    pub display_search: String,
+    #[serde(skip)]
+    pub display_json: String,
+    #[serde(skip)]
+    pub display_error: Option<String>,
+}
+
+impl Default for AddressBook {
+    fn default() -> Self {
+        Self {
+            entries: vec![
+                AddressEntry::new(WSOL_ACCOUNT, "WSOL mint", "mint"),
+                AddressEntry::new(
+                    VYBE_STAKE_VALIDATOR,
+                    "Vybe staking validator",
+                    "vybe, validator",
+                ),
+                AddressEntry::new(VYBE_TOKEN_ACCOUNT, "Vybe token account", "vybe, token"),
+                AddressEntry::new(WYATT_TEST_ACCOUNT, "Wyatt's test wallet", "wallet, test"),
+            ],
+            file_name: "address_book.json".to_string(),
+            display_search: "".to_string(),
+            display_json: "".to_string(),
+            display_error: None,
+        }
+    }
+}
+
+impl AddressBook {
+    /// The entry for the bytes, if they are a known address
+    pub fn lookup(&self, bytes: &[u8]) -> Option<&AddressEntry> {
+        if bytes.len() != 32 {
+            return None;
+        }
+        let address = bs58::encode(bytes).into_string();
+        self.entries
+            .iter()
+            .find(|entry| entry.address.trim() == address)
+    }
+
+    /// Search and pick an address. Returns the bytes of the one clicked.
+    pub fn ui(&mut self, ui: &mut Ui, current: &[u8]) -> Option<Vec<u8>> {
+        let mut selected = None;
+
+        ui.add(
+            TextEdit::singleline(&mut self.display_search)
+                .hint_text("Search labels, tags, notes")
+                .desired_width(180.0),
+        );
+        ScrollArea::vertical()
+            .id_source("address_book_entries")
+            .max_height(150.0)
+            .show(ui, |ui| {
+                for entry in &self.entries {
+                    if !entry.matches(&self.display_search) {
+                        continue;
+                    }
+                    let mut hover = entry.address.clone();
+                    for tag in entry.tags() {
+                        hover.push_str(&format!("\n#{}", tag));
+                    }
+                    if !entry.notes.is_empty() {
+                        hover.push_str(&format!("\n{}", entry.notes));
+                    }
+                    if ui.button(&entry.label).on_hover_text(hover).clicked() {
+                        match bs58::decode(entry.address.trim()).into_vec() {
+                            Ok(bytes) => selected = Some(bytes),
+                            Err(e) => self.display_error = Some(format!("{}: {}", entry.label, e)),
+                        }
+                    }
+                }
+            });
+
+        if current.len() == 32
+            && self.lookup(current).is_none()
+            && ui.button("Add current address").clicked()
+        {
+            let address = bs58::encode(current).into_string();
+            self.entries
+                .push(AddressEntry::new(&address, "New address", ""));
+        }
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+
+        ui.collapsing("Edit address book", |ui| self.edit_ui(ui));
+        selected
+    }
+
+    fn edit_ui(&mut self, ui: &mut Ui) {
+        let mut removed = None;
+        Grid::new("address_book").striped(true).show(ui, |ui| {
+            ui.label("Label");
+            ui.label("Address");
+            ui.label("Tags");
+            ui.label("Notes");
+            ui.end_row();
+            for (index, entry) in self.entries.iter_mut().enumerate() {
+                ui.text_edit_singleline(&mut entry.label);
+                ui.text_edit_singleline(&mut entry.address);
+                ui.text_edit_singleline(&mut entry.tags);
+                ui.text_edit_singleline(&mut entry.notes);
+                if ui.button("Remove").clicked() {
+                    removed = Some(index);
+                }
+                ui.end_row();
+            }
+        });
+        if let Some(index) = removed {
+            self.entries.remove(index);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Export JSON").clicked() {
+                match serde_json::to_string_pretty(&self.entries) {
+                    Ok(json) => {
+                        ui.output_mut(|o| o.copied_text = json.clone());
+                        self.display_json = json;
+                    }
+                    Err(e) => self.display_error = Some(e.to_string()),
+                }
+            }
+            if ui.button("Import JSON").clicked() {
+                self.import(&self.display_json.clone());
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("File: ");
+            ui.text_edit_singleline(&mut self.file_name);
+            if ui.button("Save as file").clicked() {
+                let saved = serde_json::to_vec_pretty(&self.entries)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| save_file(&self.file_name, &json));
+                self.display_error = saved.err();
+            }
+        });
+        ui.add(
+            TextEdit::multiline(&mut self.display_json)
+                .code_editor()
+                .desired_rows(3)
+                .hint_text("Exported entries, or entries to import"),
+        );
+    }
+
+    /// Merge entries from JSON. Imported entries replace existing ones with the same address.
+    fn import(&mut self, json: &str) {
+        let imported: Vec<AddressEntry> = match serde_json::from_str(json) {
+            Ok(imported) => imported,
+            Err(e) => {
+                self.display_error = Some(format!("Invalid address book JSON: {}", e));
+                return;
+            }
+        };
+        for entry in imported {
+            match self
+                .entries
+                .iter_mut()
+                .find(|existing| existing.address.trim() == entry.address.trim())
+            {
+                Some(existing) => *existing = entry,
+                None => self.entries.push(entry),
+            }
+        }
+        self.display_error = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn lookup_search_and_import() {
+        let mut book = super::AddressBook::default();
+        let wsol = bs58::decode(crate::WSOL_ACCOUNT).into_vec().unwrap();
+        assert_eq!(book.lookup(&wsol).unwrap().label, "WSOL mint");
+        assert!(book.entries[1].matches("VALIDATOR"));
+
+        let json = format!(
+            r#"[{{ "address": "{}", "label": "Wrapped SOL", "tags": "mint, native" }}]"#,
+            crate::WSOL_ACCOUNT
+        );
+        book.import(&json);
+        assert_eq!(book.entries.len(), 4);
+        assert_eq!(book.lookup(&wsol).unwrap().label, "Wrapped SOL");
+        assert_eq!(
+            book.entries[0].tags().collect::<Vec<_>>(),
+            ["mint", "native"]
+        );
+    }
+}
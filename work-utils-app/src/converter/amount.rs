@@ -0,0 +1,240 @@
+use egui::*;
+use primitive_types::U256;
+
+use super::base58_bytes::BaseBytesConverter;
+
+/// Decimal presets. SOL is 9 decimals of lamports.
+const PRESETS: [(&str, u8); 3] = [("Lamports / SOL", 9), ("USDC / USDT", 6), ("Integer", 0)];
+
+/// Most decimals a U256 amount can have while still holding a whole unit (10^77 < U256::MAX)
+const MAX_DECIMALS: u8 = 77;
+
+/// Insert a comma every three digits of an integer
+fn thousands(digits: &str) -> String {
+    let mut separated = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            separated.push(',');
+        }
+        separated.push(digit);
+    }
+    separated
+}
+
+/// Base units as a UI amount, without trailing zeros in the fraction
+pub fn format_ui_amount(raw: U256, decimals: u8, separators: bool) -> String {
+    let digits = format!("{:0>width$}", raw, width = decimals as usize + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals as usize);
+    let integer = match separators {
+        true => thousands(integer),
+        false => integer.to_string(),
+    };
+    match fraction.trim_end_matches('0') {
+        "" => integer,
+        fraction => format!("{}.{}", integer, fraction),
+    }
+}
+
+pub fn format_raw_amount(raw: U256, separators: bool) -> String {
+    match separators {
+        true => thousands(&raw.to_string()),
+        false => raw.to_string(),
+    }
+}
+
+/// Digits with thousands separators, underscores and spaces removed
+fn strip_separators(input: &str) -> String {
+    input
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ',' | '_' | ' '))
+        .collect()
+}
+
+pub fn parse_raw_amount(input: &str) -> Result<U256, String> {
+    let digits = strip_separators(input);
+    U256::from_dec_str(&digits).map_err(|e| format!("Invalid amount {}: {:?}", input.trim(), e))
+}
+
+/// A UI amount as exact base units. Fails rather than rounds if there are too many decimal places.
+pub fn parse_ui_amount(input: &str, decimals: u8) -> Result<U256, String> {
+    let digits = strip_separators(input);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+    if fraction.len() > decimals as usize {
+        return Err(format!(
+            "{} has more than {} decimal places",
+            input.trim(),
+            decimals
+        ));
+    }
+    if integer.is_empty() && fraction.is_empty() {
+        return Err("Empty amount".to_string());
+    }
+
+    let fraction = format!("{:0<width$}", fraction, width = decimals as usize);
+    parse_raw_amount(&format!("{}{}", integer, fraction))
+        .map_err(|_| format!("Invalid amount {}", input.trim()))
+}
+
+/// Converts between integer base units and UI amounts with any number of decimals
+pub struct AmountConverter {
+    pub display_raw: String,
+    pub display_ui: String,
+    pub decimals: u8,
+    pub separators: bool,
+
+    pub display_error: Option<String>,
+    pub raw: Option<U256>,
+}
+
+impl Default for AmountConverter {
+    fn default() -> Self {
+        Self {
+            display_raw: "".to_string(),
+            display_ui: "".to_string(),
+            decimals: 9,
+            separators: true,
+            display_error: None,
+            raw: None,
+        }
+    }
+}
+
+impl AmountConverter {
+    pub fn ui(&mut self, ui: &mut Ui, byte_converter: &mut BaseBytesConverter) {
+        let mut reformat = false;
+        ui.horizontal(|ui| {
+            ui.label("Decimals: ");
+            for (name, decimals) in PRESETS {
+                reformat |= ui
+                    .selectable_value(&mut self.decimals, decimals, name)
+                    .changed();
+            }
+            reformat |= ui
+                .add(DragValue::new(&mut self.decimals).clamp_range(0..=MAX_DECIMALS))
+                .changed();
+            reformat |= ui
+                .checkbox(&mut self.separators, "Thousands separators")
+                .changed();
+        });
+
+        Grid::new("amount_converter").show(ui, |ui| {
+            ui.label("Base units: ");
+            if ui.text_edit_singleline(&mut self.display_raw).changed() {
+                self.set_raw(parse_raw_amount(&self.display_raw), false);
+            }
+            ui.end_row();
+
+            ui.label("UI amount: ");
+            if ui.text_edit_singleline(&mut self.display_ui).changed() {
+                let raw = parse_ui_amount(&self.display_ui, self.decimals);
+                self.set_raw(raw, true);
+            }
+            ui.end_row();
+        });
+        if let (true, Some(raw)) = (reformat, self.raw) {
+            self.load(raw);
+        }
+
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+
+        ui.horizontal(|ui| {
+            let raw_u64 = self
+                .raw
+                .filter(|raw| raw.bits() <= 64)
+                .map(|raw| raw.low_u64());
+            match raw_u64 {
+                Some(raw) => {
+                    ui.monospace(format!("u64 LE bytes: {}", hex::encode(raw.to_le_bytes())));
+                    if ui.button("Send to byte converter").clicked() {
+                        byte_converter.update_texts(raw.to_le_bytes().to_vec());
+                    }
+                }
+                None if self.raw.is_some() => {
+                    ui.label("Does not fit in a u64");
+                }
+                None => {}
+            }
+
+            if let Ok(bytes) = <[u8; 8]>::try_from(byte_converter.bytes.as_slice()) {
+                if ui.button("Use current bytes as u64 LE").clicked() {
+                    self.load(U256::from(u64::from_le_bytes(bytes)));
+                }
+            }
+        });
+    }
+
+    /// Show an amount in both fields
+    fn load(&mut self, raw: U256) {
+        self.raw = Some(raw);
+        self.display_error = None;
+        self.display_raw = format_raw_amount(raw, self.separators);
+        self.display_ui = format_ui_amount(raw, self.decimals, self.separators);
+    }
+
+    /// Update the amount and the field that was not edited
+    fn set_raw(&mut self, raw: Result<U256, String>, from_ui: bool) {
+        match raw {
+            Ok(raw) => {
+                self.raw = Some(raw);
+                self.display_error = None;
+                if from_ui {
+                    self.display_raw = format_raw_amount(raw, self.separators);
+                } else {
+                    self.display_ui = format_ui_amount(raw, self.decimals, self.separators);
+                }
+            }
+            Err(e) => {
+                self.raw = None;
+                self.display_error = Some(e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    #[test]
+    fn ui_amount_round_trip() {
+        let raw = U256::from(1_234_567_890_123u64);
+        assert_eq!(super::format_ui_amount(raw, 9, true), "1,234.567890123");
+        assert_eq!(super::format_ui_amount(raw, 0, false), "1234567890123");
+        assert_eq!(
+            super::format_ui_amount(U256::from(5), 9, true),
+            "0.000000005"
+        );
+        assert_eq!(
+            super::format_ui_amount(U256::from(1_500_000_000u64), 9, true),
+            "1.5"
+        );
+
+        assert_eq!(super::parse_ui_amount("1,234.567890123", 9), Ok(raw));
+        assert_eq!(super::parse_ui_amount(".5", 6), Ok(U256::from(500_000)));
+        assert!(super::parse_ui_amount("0.0000001", 6).is_err());
+        assert_eq!(
+            super::parse_raw_amount("1_000 000"),
+            Ok(U256::from(1_000_000))
+        );
+        assert_eq!(
+            super::parse_ui_amount(&super::format_ui_amount(U256::MAX, 18, true), 18),
+            Ok(U256::MAX)
+        );
+    }
+
+    #[test]
+    fn max_decimals() {
+        let decimals = super::MAX_DECIMALS;
+        let one = super::parse_ui_amount("1", decimals).unwrap();
+        assert_eq!(one, U256::exp10(decimals as usize));
+        assert_eq!(super::format_ui_amount(one, decimals, false), "1");
+
+        let max = super::format_ui_amount(U256::MAX, decimals, false);
+        assert!(max.starts_with("1.1579"));
+        assert_eq!(super::parse_ui_amount(&max, decimals), Ok(U256::MAX));
+        assert!(super::parse_ui_amount("1", decimals + 1).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use egui::*;
+use sha2::{Digest, Sha256};
+
+use super::base58_bytes::BaseBytesConverter;
+
+/// Namespaces Anchor hashes names under, with an example name for each
+const NAMESPACES: [(&str, &str); 3] = [
+    ("global", "instruction name, snake_case"),
+    ("account", "account type, PascalCase"),
+    ("event", "event type, PascalCase"),
+];
+
+/// The first 8 bytes of `sha256("<namespace>:<name>")`
+pub fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("{}:{}", namespace, name));
+    let mut discriminator = [0; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+/// Anchor discriminators for instructions, accounts and events,
+/// matched against the start of the byte converter's buffer
+#[derive(Default)]
+pub struct DiscriminatorCalculator {
+    pub display_names: [String; NAMESPACES.len()],
+}
+
+impl DiscriminatorCalculator {
+    pub fn ui(&mut self, ui: &mut Ui, byte_converter: &mut BaseBytesConverter) {
+        let prefix = byte_converter
+            .bytes
+            .get(..8)
+            .and_then(|prefix| <[u8; 8]>::try_from(prefix).ok());
+        match prefix {
+            Some(prefix) => {
+                ui.monospace(format!("Current bytes start with {}", hex::encode(prefix)))
+            }
+            None => ui.monospace("Current bytes are shorter than 8 bytes"),
+        };
+
+        Grid::new("anchor_discriminators")
+            .striped(true)
+            .show(ui, |ui| {
+                for ((namespace, hint), name) in NAMESPACES.iter().zip(&mut self.display_names) {
+                    ui.label(format!("{}:", namespace));
+                    ui.add(TextEdit::singleline(name).hint_text(*hint));
+                    if name.is_empty() {
+                        ui.end_row();
+                        continue;
+                    }
+
+                    let discriminator = discriminator(namespace, name);
+                    ui.vertical(|ui| {
+                        ui.monospace(hex::encode(discriminator));
+                        ui.monospace(format!("{:?}", discriminator));
+                        ui.monospace(bs58::encode(discriminator).into_string());
+                    });
+                    if prefix == Some(discriminator) {
+                        ui.colored_label(Color32::LIGHT_GREEN, "Matches current bytes");
+                    } else {
+                        ui.label("No match");
+                    }
+                    if ui.button("Send to byte converter").clicked() {
+                        byte_converter.update_texts(discriminator.to_vec());
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn discriminators() {
+        assert_eq!(
+            super::discriminator("global", "initialize"),
+            [175, 175, 109, 31, 13, 152, 155, 237]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use egui::*;
+
+use super::base58_bytes::BaseBytesConverter;
+use super::solana::{find_associated_token_address, pubkey_from_base58};
+use crate::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, WSOL_ACCOUNT};
+
+/// Token programs an associated token account can belong to
+const TOKEN_PROGRAMS: [(&str, &str); 2] = [
+    ("SPL Token", TOKEN_PROGRAM_ID),
+    ("Token-2022", TOKEN_2022_PROGRAM_ID),
+];
+
+/// Associated token account addresses of a wallet and mint, for each token program
+pub struct AtaDeriver {
+    pub display_wallet: String,
+    pub display_mint: String,
+
+    pub display_error: Option<String>,
+    pub results: Vec<(&'static str, [u8; 32])>,
+}
+
+impl Default for AtaDeriver {
+    fn default() -> Self {
+        Self {
+            display_wallet: "".to_string(),
+            display_mint: WSOL_ACCOUNT.to_string(),
+            display_error: None,
+            results: Vec::new(),
+        }
+    }
+}
+
+impl AtaDeriver {
+    pub fn ui(&mut self, ui: &mut Ui, byte_converter: &mut BaseBytesConverter) {
+        let mut changed = false;
+
+        Grid::new("ata_inputs").show(ui, |ui| {
+            ui.label("Wallet: ");
+            changed |= ui.text_edit_singleline(&mut self.display_wallet).changed();
+            ui.end_row();
+
+            ui.label("Mint: ");
+            changed |= ui.text_edit_singleline(&mut self.display_mint).changed();
+            if ui.button("WSOL").clicked() {
+                self.display_mint = WSOL_ACCOUNT.to_string();
+                changed = true;
+            }
+            ui.end_row();
+        });
+
+        if changed {
+            self.derive();
+        }
+
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+        Grid::new("ata_results").striped(true).show(ui, |ui| {
+            for (program, address) in &self.results {
+                let address_base58 = bs58::encode(address).into_string();
+                ui.label(format!("{}: ", program));
+                ui.monospace(&address_base58);
+                if ui.button("Copy").clicked() {
+                    ui.output_mut(|o| o.copied_text = address_base58.clone());
+                }
+                if ui.button("Send to byte converter").clicked() {
+                    byte_converter.update_texts(address.to_vec());
+                }
+                ui.end_row();
+            }
+        });
+    }
+
+    fn derive(&mut self) {
+        self.results.clear();
+        self.display_error = None;
+
+        let wallet = match pubkey_from_base58(&self.display_wallet) {
+            Ok(wallet) => wallet,
+            Err(e) => {
+                self.display_error = Some(format!("Wallet: {}", e));
+                return;
+            }
+        };
+        let mint = match pubkey_from_base58(&self.display_mint) {
+            Ok(mint) => mint,
+            Err(e) => {
+                self.display_error = Some(format!("Mint: {}", e));
+                return;
+            }
+        };
+
+        for (program, program_id) in TOKEN_PROGRAMS {
+            let result = pubkey_from_base58(program_id)
+                .and_then(|program_id| find_associated_token_address(&wallet, &mint, &program_id));
+            match result {
+                Ok(address) => self.results.push((program, address)),
+                Err(e) => {
+                    self.display_error = Some(format!("{}: {}", program, e));
+                    return;
+                }
+            }
+        }
+    }
+}
@@ -1,38 +1,114 @@
-use egui::*;
-use primitive_types::U256;
+use base64::{
+    alphabet,
+    engine::{
+        general_purpose::{
+            GeneralPurposeConfig, STANDARD as BASE64_STANDARD,
+            STANDARD_NO_PAD as BASE64_STANDARD_NO_PAD, URL_SAFE as BASE64_URL_SAFE,
+            URL_SAFE_NO_PAD as BASE64_URL_SAFE_NO_PAD,
+        },
+        DecodePaddingMode, GeneralPurpose,
+    },
+    Engine,
+};
+use egui::{text::LayoutJob, *};
+use primitive_types::{U256, U512};
+use std::ops::Range;
 
-use crate::{VYBE_STAKE_VALIDATOR, VYBE_TOKEN_ACCOUNT, WSOL_ACCOUNT, WYATT_TEST_ACCOUNT};
+use super::address_book::AddressBook;
+use super::explorers::ExplorerRegistry;
+use super::hexdump::HexDump;
+use super::known_ids::known_id;
+use super::solana::is_on_curve;
+use crate::files::{dropped_file_save_name, read_dropped_file, save_file};
 
 #[derive(PartialEq)]
 pub struct BaseBytesConverter {
+    pub display_smart_paste: String,
+    pub paste_candidates: Vec<PasteCandidate>,
+
     pub display_base58: String,
     pub display_hex: String,
+    pub display_base64: String,
+    pub display_base64_no_pad: String,
+    pub display_base64_url: String,
+    pub display_base64_url_no_pad: String,
+    pub display_binary: String,
+    pub display_byte_list_octal: String,
     pub display_byte_list_u8: String,
     pub display_byte_list_i8: String,
     pub display_u256: String,
+    pub display_i256: String,
+    pub display_u512: String,
+    pub display_rust_literal: String,
+    pub display_c_literal: String,
+    pub display_python_literal: String,
+    pub display_utf8: String,
+    pub display_integers: [String; INTEGER_KINDS.len()],
+    pub display_code_literals: [String; CODE_LITERALS.len()],
 
     pub display_error: Option<String>,
+    /// The row that failed to parse, and the offending characters in it
+    pub display_error_span: Option<(String, Range<usize>)>,
 
     pub use_commas: bool,
+    pub little_endian: bool,
+
+    /// File name (or path, on native) to save the bytes to
+    pub file_name: String,
+
+    /// The bytes currently being displayed
+    pub bytes: Vec<u8>,
+
+    pub hexdump: HexDump,
 }
 
 impl Default for BaseBytesConverter {
     fn default() -> Self {
         Self {
+            display_smart_paste: "".to_string(),
+            paste_candidates: Vec::new(),
+
             display_base58: "".to_string(),
             display_hex: "".to_string(),
+            display_base64: "".to_string(),
+            display_base64_no_pad: "".to_string(),
+            display_base64_url: "".to_string(),
+            display_base64_url_no_pad: "".to_string(),
+            display_binary: "".to_string(),
+            display_byte_list_octal: "".to_string(),
             display_byte_list_i8: "".to_string(),
             display_byte_list_u8: "".to_string(),
             display_u256: "".to_string(),
+            display_i256: "".to_string(),
+            display_u512: "".to_string(),
+            display_rust_literal: "".to_string(),
+            display_c_literal: "".to_string(),
+            display_python_literal: "".to_string(),
+            display_utf8: "".to_string(),
+            display_integers: Default::default(),
+            display_code_literals: Default::default(),
 
             display_error: None,
+            display_error_span: None,
             use_commas: false,
+            little_endian: true,
+
+            file_name: "bytes.bin".to_string(),
+
+            bytes: Vec::new(),
+
+            hexdump: HexDump::default(),
         }
     }
 }
 
 impl BaseBytesConverter {
-    pub fn ui(&mut self, ui: &mut Ui) {
+    pub fn ui(
+        &mut self,
+        ui: &mut Ui,
+        explorers: &mut ExplorerRegistry,
+        address_book: &mut AddressBook,
+    ) {
         ui.label("Byte array converter to common formats");
         ui.horizontal(|ui| {
             ui.vertical(|ui| {
@@ -43,188 +119,179 @@ impl BaseBytesConverter {
                     ui.label(" ");
                 }
 
-                // Base58 input and display
-                ui.horizontal(|ui| {
-                    ui.label("Base58: ");
-                    let response = ui.text_edit_singleline(&mut self.display_base58);
-                    if response.changed() {
-                        match parse_base58(&self.display_base58) {
-                            Ok(s) => self.update_texts(s),
-                            Err(e) => self.display_error = Some(e),
-                        }
-                    }
+                self.smart_paste_ui(ui);
 
-                    if ui.button("Copy").clicked() {
-                        ui.output_mut(|o| o.copied_text = self.display_base58.clone());
-                    }
-                });
+                let error_span = self.display_error_span.clone();
+                let error_span = |label: &str| match &error_span {
+                    Some((row, span)) if row == label => Some(span.clone()),
+                    _ => None,
+                };
+                let warn_color = ui.visuals().warn_fg_color;
+                let mut parsed = None;
 
-                // Hex input and display
-                ui.horizontal(|ui| {
-                    ui.label("Hex: ");
-                    let response = ui.text_edit_singleline(&mut self.display_hex);
-                    if response.changed() {
-                        match parse_hex(&self.display_hex) {
-                            Ok(s) => self.update_texts(s),
-                            Err(e) => self.display_error = Some(e),
+                // Base58, noting whether 32 bytes are a wallet or a PDA, and their label if known
+                let curve_badge = <[u8; 32]>::try_from(self.bytes.as_slice())
+                    .ok()
+                    .map(|bytes| {
+                        if is_on_curve(&bytes) {
+                            RichText::new("On curve (wallet)").color(Color32::LIGHT_GREEN)
+                        } else {
+                            RichText::new("Off curve (PDA)").color(Color32::LIGHT_BLUE)
                         }
+                    });
+                let book_label = address_book
+                    .lookup(&self.bytes)
+                    .map(|entry| RichText::new(&entry.label).strong());
+                let notes = curve_badge.into_iter().chain(book_label);
+                let label = "Base58: ";
+                let text = &mut self.display_base58;
+                let span = error_span(label);
+                if let Some(p) = representation_row(ui, label, text, notes, span, parse_base58) {
+                    parsed = Some((label.to_string(), p));
+                }
+                if let Some(known) = known_id(&self.bytes) {
+                    let description = format!("{}: {}", known.name, known.description);
+                    ui.label(RichText::new(description).italics());
+                }
+
+                let rows: Vec<(&str, &mut String, ParseFn)> = vec![
+                    ("Hex: ", &mut self.display_hex, parse_hex),
+                    ("Base64: ", &mut self.display_base64, parse_base64),
+                    (
+                        "Base64 (no padding): ",
+                        &mut self.display_base64_no_pad,
+                        parse_base64,
+                    ),
+                    (
+                        "Base64url: ",
+                        &mut self.display_base64_url,
+                        parse_base64_url,
+                    ),
+                    (
+                        "Base64url (no padding): ",
+                        &mut self.display_base64_url_no_pad,
+                        parse_base64_url,
+                    ),
+                    ("Binary: ", &mut self.display_binary, parse_binary),
+                    (
+                        "Byte list (octal): ",
+                        &mut self.display_byte_list_octal,
+                        parse_byte_list_octal,
+                    ),
+                    (
+                        "Byte list (u8): ",
+                        &mut self.display_byte_list_u8,
+                        parse_byte_list_u8,
+                    ),
+                    (
+                        "Byte list (i8): ",
+                        &mut self.display_byte_list_i8,
+                        parse_byte_list_i8,
+                    ),
+                    ("U256: ", &mut self.display_u256, parse_u256),
+                    ("I256: ", &mut self.display_i256, parse_i256),
+                    ("U512: ", &mut self.display_u512, parse_u512),
+                    (
+                        "Rust literal: ",
+                        &mut self.display_rust_literal,
+                        parse_escaped_literal,
+                    ),
+                    (
+                        "C literal: ",
+                        &mut self.display_c_literal,
+                        parse_escaped_literal,
+                    ),
+                    (
+                        "Python literal: ",
+                        &mut self.display_python_literal,
+                        parse_escaped_literal,
+                    ),
+                ];
+
+                for (label, text, parse) in rows {
+                    let span = error_span(label);
+                    if let Some(p) = representation_row(ui, label, text, None, span, parse) {
+                        parsed = Some((label.to_string(), p));
                     }
-                    if ui.button("Copy").clicked() {
-                        ui.output_mut(|o| o.copied_text = self.display_hex.clone());
-                    }
+                }
+
+                // The text is displayed lossily if the bytes are not valid UTF-8
+                let warning = std::str::from_utf8(&self.bytes).err().map(|e| {
+                    RichText::new(format!("Invalid UTF-8 at byte {}", e.valid_up_to()))
+                        .color(warn_color)
                 });
+                let label = "Text (UTF-8): ";
+                let text = &mut self.display_utf8;
+                if let Some(p) = representation_row(ui, label, text, warning, None, parse_utf8) {
+                    parsed = Some((label.to_string(), p));
+                }
 
-                // Byte list (u8) input and display
+                if let Some((label, parsed)) = parsed {
+                    self.apply_parsed(label, parsed);
+                }
+
+                // Fixed-width integers, in the selected byte order
                 ui.horizontal(|ui| {
-                    ui.label("Byte list (u8): ");
-                    let response = ui.text_edit_singleline(&mut self.display_byte_list_u8);
-                    if response.changed() {
-                        match parse_byte_list_u8(&self.display_byte_list_u8) {
-                            Ok(byte_list) => self.update_texts(byte_list),
-                            Err(e) => self.display_error = Some(e),
-                        }
-                    }
-                    if ui.button("Copy").clicked() {
-                        ui.output_mut(|o| o.copied_text = self.display_byte_list_u8.clone());
+                    ui.label("Integer byte order: ");
+                    let le = ui.selectable_value(&mut self.little_endian, true, "Little endian");
+                    let be = ui.selectable_value(&mut self.little_endian, false, "Big endian");
+                    if le.clicked() || be.clicked() {
+                        self.update_texts(self.bytes.clone());
                     }
                 });
 
-                // Byte list (i8) input and display
-                ui.horizontal(|ui| {
-                    ui.label("Byte list (i8): ");
-                    let response = ui.text_edit_singleline(&mut self.display_byte_list_i8);
-                    if response.changed() {
-                        match parse_byte_list_i8(&self.display_byte_list_i8) {
-                            Ok(byte_list) => self.update_texts(byte_list),
-                            Err(e) => self.display_error = Some(e),
-                        }
+                let mut parsed = None;
+                for (kind, text) in INTEGER_KINDS.iter().zip(self.display_integers.iter_mut()) {
+                    let label = format!("{}: ", kind.label);
+                    let warning = (!self.bytes.is_empty() && self.bytes.len() != kind.width)
+                        .then(|| format!("Needs {} bytes, have {}", kind.width, self.bytes.len()))
+                        .map(|warning| RichText::new(warning).color(warn_color));
+                    let span = error_span(&label);
+                    let little_endian = self.little_endian;
+                    if let Some(p) = representation_row(ui, &label, text, warning, span, |text| {
+                        kind.parse(text, little_endian)
+                    }) {
+                        parsed = Some((label, p));
                     }
-                    if ui.button("Copy").clicked() {
-                        ui.output_mut(|o| o.copied_text = self.display_byte_list_i8.clone());
-                    }
-                });
+                }
 
-                // U256 input and display
-                ui.horizontal(|ui| {
-                    ui.label("U256: ");
-                    let response = ui.text_edit_singleline(&mut self.display_u256);
-                    if response.changed() {
-                        match parse_u256(&self.display_u256) {
-                            Ok(u256) => self.update_texts(u256),
-                            Err(e) => self.display_error = Some(e),
+                ui.collapsing("Code literals", |ui| {
+                    let literals = CODE_LITERALS
+                        .iter()
+                        .zip(self.display_code_literals.iter_mut());
+                    for (literal, text) in literals {
+                        let warning = (!self.bytes.is_empty()
+                            && literal.format(&self.bytes).is_none())
+                        .then(|| format!("Needs 32 bytes, have {}", self.bytes.len()))
+                        .map(|warning| RichText::new(warning).color(warn_color));
+                        let label = literal.label();
+                        if let Some(p) = representation_row(
+                            ui,
+                            label,
+                            text,
+                            warning,
+                            error_span(label),
+                            parse_code_literal,
+                        ) {
+                            parsed = Some((label.to_string(), p));
                         }
                     }
-                    if ui.button("Copy").clicked() {
-                        ui.output_mut(|o| o.copied_text = self.display_u256.clone());
-                    }
                 });
+
+                if let Some((label, parsed)) = parsed {
+                    self.apply_parsed(label, parsed);
+                }
             });
             ui.vertical(|ui| {
                 ui.label(" ");
-                if !self.display_base58.is_empty() {
-                    let solscan_url = format!("https://solscan.io/account/{}", self.display_base58);
-                    let solscan_tx_url = format!("https://solscan.io/tx/{}", self.display_base58);
-                    let explorer_url = format!(
-                        "https://explorer.solana.com/address/{}",
-                        self.display_base58
-                    );
-                    let solana_fm_url =
-                        format!("https://solana.fm/address/{}", self.display_base58);
-                    
-                    let vybe_prod_url = format!(
-                        "https://vybe.fyi/wallets/{}",
-                        self.display_base58
-                    );
-
-                    let vybe_staging_url = format!(
-                        "https://alpha.vybeapp.xyz/wallets/{}",
-                        self.display_base58
-                    );
-
-                    // now do it as a button that links out
-                    if ui.button("Open in Solscan as account").clicked() {
-                        ui.ctx().open_url(egui::OpenUrl {
-                            url: solscan_url,
-                            new_tab: true,
-                        });
-                    }
-
-                    if ui.button("Open in Solscan as transaction").clicked() {
-                        ui.ctx().open_url(egui::OpenUrl {
-                            url: solscan_tx_url,
-                            new_tab: true,
-                        });
-                    }
-
-                    if ui.button("Open in Solana Explorer").clicked() {
-                        ui.ctx().open_url(egui::OpenUrl {
-                            url: explorer_url,
-                            new_tab: true,
-                        });
-                    }
-
-                    if ui.button("Open in Solana.fm").clicked() {
-                        ui.ctx().open_url(egui::OpenUrl {
-                            url: solana_fm_url,
-                            new_tab: true,
-                        });
-                    }
-
-                    if ui.button("Open in vybe prod").clicked() {
-                        ui.ctx().open_url(egui::OpenUrl {
-                            url: vybe_prod_url,
-                            new_tab: true,
-                        });
-                    }
-
-                    if ui.button("Open in vybe staging").clicked() {
-                        ui.ctx().open_url(egui::OpenUrl {
-                            url: vybe_staging_url,
-                            new_tab: true,
-                        });
-                    }
-
-                }
+                explorers.ui(ui, &self.bytes);
             });
 
             ui.vertical(|ui| {
-                ui.label("Constant accounts");
-                    if ui.button("WSOL mint").clicked() {
-                        let new_b58 = WSOL_ACCOUNT;
-                        self.display_base58 = new_b58.to_string();
-                        match parse_base58(new_b58) {
-                            Ok(s) => self.update_texts(s),
-                            Err(e) => self.display_error = Some(e),
-                        }
-                    }
-
-                    if ui.button("Vybe staking validator").clicked() {
-                        let new_b58 = VYBE_STAKE_VALIDATOR;
-                        self.display_base58 = new_b58.to_string();
-                        match parse_base58(new_b58) {
-                            Ok(s) => self.update_texts(s),
-                            Err(e) => self.display_error = Some(e),
-                        }
-                    }
-
-                    if ui.button("Vybe token account").clicked() {
-                        let new_b58 = VYBE_TOKEN_ACCOUNT;
-                        self.display_base58 = new_b58.to_string();
-                        match parse_base58(new_b58) {
-                            Ok(s) => self.update_texts(s),
-                            Err(e) => self.display_error = Some(e),
-                        }
-                    }
-
-                    
-                    if ui.button("Wyatt's test wallet").clicked() {
-                        let new_b58 = WYATT_TEST_ACCOUNT;
-                        self.display_base58 = new_b58.to_string();
-                        match parse_base58(new_b58) {
-                            Ok(s) => self.update_texts(s),
-                            Err(e) => self.display_error = Some(e),
-                        }
-                    }
+                ui.label("Address book");
+                if let Some(bytes) = address_book.ui(ui, &self.bytes) {
+                    self.update_texts(bytes);
+                }
             });
         });
 
@@ -252,24 +319,156 @@ impl BaseBytesConverter {
                 );
             }
         });
+
+        ui.horizontal(|ui| {
+            ui.label("File: ");
+            ui.text_edit_singleline(&mut self.file_name);
+            if ui.button("Save as file").clicked() {
+                if let Err(e) = save_file(&self.file_name, &self.bytes) {
+                    self.display_error = Some(e);
+                }
+            }
+            ui.label("Drop a file onto the window to load its bytes.");
+        });
+
+        ui.collapsing("Bit grid", |ui| self.bit_grid_ui(ui));
+        ui.collapsing("Hexdump", |ui| self.hexdump.ui(ui, &self.bytes));
+    }
+
+    /// A single input that accepts any supported format, guessing which one it is
+    fn smart_paste_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Paste anything: ");
+            let response = ui.text_edit_singleline(&mut self.display_smart_paste);
+            if response.changed() {
+                self.paste_candidates = classify_input(&self.display_smart_paste);
+                match self.paste_candidates.first() {
+                    Some(best) => self.update_texts(best.bytes.clone()),
+                    None if self.display_smart_paste.trim().is_empty() => {}
+                    None => {
+                        self.display_error =
+                            Some("Could not recognize the pasted format".to_string())
+                    }
+                }
+            }
+        });
+
+        // Alternatives, most plausible first. The first one is applied automatically.
+        if !self.paste_candidates.is_empty() {
+            let mut selected = None;
+            ui.horizontal_wrapped(|ui| {
+                ui.label("Interpret as: ");
+                for candidate in &self.paste_candidates {
+                    let label = format!("{} ({} bytes)", candidate.format, candidate.bytes.len());
+                    let is_current = candidate.bytes == self.bytes;
+                    if ui.selectable_label(is_current, label).clicked() {
+                        selected = Some(candidate.bytes.clone());
+                    }
+                }
+            });
+            if let Some(bytes) = selected {
+                self.update_texts(bytes);
+            }
+        }
+    }
+
+    /// Update to newly parsed bytes, or show which characters of the row failed to parse
+    fn apply_parsed(&mut self, label: String, parsed: Result<Vec<u8>, ParseError>) {
+        match parsed {
+            Ok(bytes) => self.update_texts(bytes),
+            Err(e) => {
+                self.display_error = Some(e.to_string());
+                self.display_error_span = e.span.map(|span| (label, span));
+            }
+        }
+    }
+
+    /// Load the contents of a dropped file as the current bytes
+    pub fn load_dropped_file(&mut self, file: &DroppedFile) {
+        match read_dropped_file(file) {
+            Ok(bytes) => {
+                self.file_name = dropped_file_save_name(file);
+                self.update_texts(bytes);
+            }
+            Err(e) => self.display_error = Some(e),
+        }
+    }
+
+    /// Every bit of the buffer, most significant bit first. Clicking a bit toggles it.
+    fn bit_grid_ui(&mut self, ui: &mut Ui) {
+        const BYTES_PER_ROW: usize = 4;
+
+        let mut toggled = None;
+        ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+            Grid::new("bit_grid").spacing([8.0, 2.0]).show(ui, |ui| {
+                for (row, chunk) in self.bytes.chunks(BYTES_PER_ROW).enumerate() {
+                    ui.monospace(format!("{:04x}", row * BYTES_PER_ROW));
+                    for (column, byte) in chunk.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.spacing_mut().item_spacing.x = 1.0;
+                            for bit in (0..8).rev() {
+                                let set = byte & (1 << bit) != 0;
+                                let label = RichText::new(if set { "1" } else { "0" }).monospace();
+                                if ui.selectable_label(set, label).clicked() {
+                                    toggled = Some((row * BYTES_PER_ROW + column, bit));
+                                }
+                            }
+                        });
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+
+        if let Some((index, bit)) = toggled {
+            let mut bytes = self.bytes.clone();
+            bytes[index] ^= 1 << bit;
+            self.update_texts(bytes);
+        }
     }
 
     /// Update texts based on a new input (Vec<u8>)
     /// input can be parsed using a helper function (parse_...  functions below)
-    fn update_texts(&mut self, input: Vec<u8>) {
+    pub fn update_texts(&mut self, input: Vec<u8>) {
         self.display_error = None;
+        self.display_error_span = None;
+
+        for (kind, text) in INTEGER_KINDS.iter().zip(self.display_integers.iter_mut()) {
+            *text = kind.render(&input, self.little_endian).unwrap_or_default();
+        }
+        for (literal, text) in CODE_LITERALS
+            .iter()
+            .zip(self.display_code_literals.iter_mut())
+        {
+            *text = literal.format(&input).unwrap_or_default();
+        }
 
         self.display_base58 = bs58::encode(&input).into_string();
         self.display_hex = hex::encode(&input);
+        self.display_base64 = BASE64_STANDARD.encode(&input);
+        self.display_base64_no_pad = BASE64_STANDARD_NO_PAD.encode(&input);
+        self.display_base64_url = BASE64_URL_SAFE.encode(&input);
+        self.display_base64_url_no_pad = BASE64_URL_SAFE_NO_PAD.encode(&input);
+
+        self.display_binary = input
+            .iter()
+            .map(|byte| format!("{:08b}", byte))
+            .collect::<Vec<_>>()
+            .join(" ");
 
+        let mut byte_list_octal = String::new();
         let mut byte_list_u8 = String::new();
         let mut byte_list_i8 = String::new();
         for byte in input.iter() {
             let comma_or_space = if self.use_commas { "," } else { " " };
+            byte_list_octal.push_str(&format!("{:03o}{comma_or_space}", byte));
             byte_list_u8.push_str(&format!("{}{comma_or_space}", byte));
             byte_list_i8.push_str(&format!("{}{comma_or_space}", *byte as i8));
         }
         // Remove the last comma or space
+        if !byte_list_octal.is_empty() {
+            byte_list_octal.pop();
+        }
         if !byte_list_u8.is_empty() {
             byte_list_u8.pop();
         }
@@ -277,80 +476,994 @@ impl BaseBytesConverter {
             byte_list_i8.pop();
         }
 
+        self.display_byte_list_octal = byte_list_octal;
         self.display_byte_list_u8 = byte_list_u8;
         self.display_byte_list_i8 = byte_list_i8;
 
-        if input.len() > 4 * 8 {
-            self.display_error = Some("Value is too large for u256".to_string());
-            self.display_u256 = "".to_string();
+        // Big integers are left empty if the value does not fit
+        self.display_u256 = if input.len() > 4 * 8 {
+            "".to_string()
+        } else {
+            U256::from_big_endian(&input).to_string()
+        };
+        self.display_i256 = format_i256(&input).unwrap_or_default();
+        self.display_u512 = if input.len() > 8 * 8 {
+            "".to_string()
+        } else {
+            U512::from_big_endian(&input).to_string()
+        };
+
+        self.display_rust_literal = format_escaped_literal(&input, LiteralSyntax::Rust);
+        self.display_c_literal = format_escaped_literal(&input, LiteralSyntax::C);
+        self.display_python_literal = format_escaped_literal(&input, LiteralSyntax::Python);
+        self.display_utf8 = String::from_utf8_lossy(&input).into_owned();
+
+        self.bytes = input;
+    }
+}
+
+/// A fixed-width integer interpretation of the whole buffer
+pub struct IntegerKind {
+    pub label: &'static str,
+    pub width: usize,
+    pub signed: bool,
+}
+
+pub const INTEGER_KINDS: [IntegerKind; 10] = [
+    IntegerKind::new("u8", 1, false),
+    IntegerKind::new("u16", 2, false),
+    IntegerKind::new("u32", 4, false),
+    IntegerKind::new("u64", 8, false),
+    IntegerKind::new("u128", 16, false),
+    IntegerKind::new("i8", 1, true),
+    IntegerKind::new("i16", 2, true),
+    IntegerKind::new("i32", 4, true),
+    IntegerKind::new("i64", 8, true),
+    IntegerKind::new("i128", 16, true),
+];
+
+impl IntegerKind {
+    const fn new(label: &'static str, width: usize, signed: bool) -> Self {
+        Self {
+            label,
+            width,
+            signed,
+        }
+    }
+
+    /// Render the bytes as this integer. None if the buffer is not exactly this width.
+    pub fn render(&self, bytes: &[u8], little_endian: bool) -> Option<String> {
+        if bytes.len() != self.width {
+            return None;
+        }
+
+        let mut le_bytes = bytes.to_vec();
+        if !little_endian {
+            le_bytes.reverse();
+        }
+
+        // Sign- or zero-extend to 128 bits
+        let negative = self.signed && le_bytes[self.width - 1] & 0x80 != 0;
+        le_bytes.resize(16, if negative { 0xff } else { 0 });
+        let le_bytes: [u8; 16] = le_bytes.try_into().ok()?;
+
+        if self.signed {
+            Some(i128::from_le_bytes(le_bytes).to_string())
         } else {
-            self.display_u256 = primitive_types::U256::from_big_endian(&input).to_string();
+            Some(u128::from_le_bytes(le_bytes).to_string())
+        }
+    }
+
+    /// Parse a decimal value into exactly this many bytes
+    pub fn parse(&self, input: &str, little_endian: bool) -> Result<Vec<u8>, ParseError> {
+        let start = input.len() - input.trim_start().len();
+        let input = input.trim();
+        let bits = self.width * 8;
+        let out_of_range = || {
+            let reason = format!("Value is out of range for {}: {}", self.label, input);
+            ParseError::at(reason, start..start + input.len())
+        };
+
+        let le_bytes = if self.signed {
+            let value = input
+                .parse::<i128>()
+                .map_err(|e| decimal_error(self.label, input, e).offset(start))?;
+            if bits < 128 && (value < -(1 << (bits - 1)) || value >= 1 << (bits - 1)) {
+                return Err(out_of_range());
+            }
+            value.to_le_bytes()
+        } else {
+            let value = input
+                .parse::<u128>()
+                .map_err(|e| decimal_error(self.label, input, e).offset(start))?;
+            if bits < 128 && value >> bits != 0 {
+                return Err(out_of_range());
+            }
+            value.to_le_bytes()
+        };
+
+        let mut result = le_bytes[..self.width].to_vec();
+        if !little_endian {
+            result.reverse();
+        }
+        Ok(result)
+    }
+}
+
+/// Source code the bytes can be exported as. All of them parse back with parse_code_literal.
+#[derive(Clone, Copy)]
+pub enum CodeLiteral {
+    RustArray,
+    RustPubkeyMacro,
+    RustPubkeyFromArray,
+    TypeScriptUint8Array,
+    TypeScriptPublicKey,
+    PythonBytes,
+    GoByteSlice,
+}
+
+pub const CODE_LITERALS: [CodeLiteral; 7] = [
+    CodeLiteral::RustArray,
+    CodeLiteral::RustPubkeyMacro,
+    CodeLiteral::RustPubkeyFromArray,
+    CodeLiteral::TypeScriptUint8Array,
+    CodeLiteral::TypeScriptPublicKey,
+    CodeLiteral::PythonBytes,
+    CodeLiteral::GoByteSlice,
+];
+
+impl CodeLiteral {
+    pub fn label(self) -> &'static str {
+        match self {
+            CodeLiteral::RustArray => "Rust [u8; N]: ",
+            CodeLiteral::RustPubkeyMacro => "Rust pubkey!: ",
+            CodeLiteral::RustPubkeyFromArray => "Rust Pubkey: ",
+            CodeLiteral::TypeScriptUint8Array => "TypeScript Uint8Array: ",
+            CodeLiteral::TypeScriptPublicKey => "TypeScript PublicKey: ",
+            CodeLiteral::PythonBytes => "Python bytes: ",
+            CodeLiteral::GoByteSlice => "Go []byte: ",
+        }
+    }
+
+    /// Format the bytes as source code. None for pubkey literals if the bytes are not 32 long.
+    pub fn format(self, input: &[u8]) -> Option<String> {
+        let list = input
+            .iter()
+            .map(|byte| byte.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let base58 = bs58::encode(input).into_string();
+        let is_pubkey = input.len() == 32;
+
+        match self {
+            CodeLiteral::RustArray => Some(format!("[u8; {}] = [{list}]", input.len())),
+            CodeLiteral::RustPubkeyMacro => is_pubkey.then(|| format!("pubkey!(\"{base58}\")")),
+            CodeLiteral::RustPubkeyFromArray => {
+                is_pubkey.then(|| format!("Pubkey::new_from_array([{list}])"))
+            }
+            CodeLiteral::TypeScriptUint8Array => Some(format!("new Uint8Array([{list}])")),
+            CodeLiteral::TypeScriptPublicKey => {
+                is_pubkey.then(|| format!("new PublicKey(\"{base58}\")"))
+            }
+            CodeLiteral::PythonBytes => Some(format!("bytes([{list}])")),
+            CodeLiteral::GoByteSlice => Some(format!("[]byte{{{list}}}")),
         }
     }
 }
 
+/// Parse any of the CodeLiteral formats back into bytes:
+/// pubkey!("So11111111111111111111111111111111111111112") -> base58 decoded
+/// [u8; 3] = [1, 2, 3] -> vec![1, 2, 3] (the last bracketed list is used)
+/// []byte{1, 2, 3} -> vec![1, 2, 3]
+fn parse_code_literal(input: &str) -> Result<Vec<u8>, ParseError> {
+    // Quoted strings are base58 pubkeys
+    let mut quoted = input.split('"');
+    if let (Some(before), Some(base58), Some(_)) = (quoted.next(), quoted.next(), quoted.next()) {
+        return parse_base58(base58).map_err(|e| e.offset(before.len() + 1));
+    }
+
+    let close = input.rfind([']', '}']).ok_or_else(|| {
+        ParseError::new("Failed to parse code literal: no byte list found".to_string())
+    })?;
+    let open = input[..close].rfind(['[', '{']).ok_or_else(|| {
+        let reason = "Failed to parse code literal: unopened byte list";
+        ParseError::at(reason, close..close + 1)
+    })?;
+    parse_byte_list_u8(&input[open + 1..close]).map_err(|e| e.offset(open + 1))
+}
+
+/// A possible interpretation of a pasted value
+#[derive(PartialEq)]
+pub struct PasteCandidate {
+    pub format: &'static str,
+    pub bytes: Vec<u8>,
+    pub score: u32,
+}
+
+/// Guess which formats a pasted value could be in, most plausible first.
+/// Scores are rough heuristics: explicit markers (0x, brackets, base64 symbols) win,
+/// and common Solana lengths (32 byte pubkeys, 64 byte signatures and keypairs) add weight.
+pub fn classify_input(input: &str) -> Vec<PasteCandidate> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    let is_digits = input.chars().all(|c| c.is_ascii_digit());
+    let is_hex_digits = input.chars().all(|c| c.is_ascii_hexdigit());
+    let is_list = input.starts_with('[') || input.contains([',', ' ']);
+    fn solana_length_bonus(bytes: &[u8]) -> u32 {
+        match bytes.len() {
+            32 | 64 => 20,
+            _ => 0,
+        }
+    }
+
+    let mut candidates = Vec::new();
+    let mut add = |format, parse: ParseFn, score: fn(&[u8]) -> u32| {
+        if let Ok(bytes) = parse(input) {
+            let score = score(&bytes);
+            candidates.push(PasteCandidate {
+                format,
+                bytes,
+                score,
+            });
+        }
+    };
+
+    if input.starts_with('[') && !input.starts_with("[\"") {
+        add("Solana keypair", parse_byte_list_u8, |bytes| {
+            match bytes.len() {
+                64 => 100,
+                _ => 0,
+            }
+        });
+    }
+    if is_list {
+        add("Byte list", parse_byte_list_u8, |_| 90);
+    }
+    if input.starts_with("0x") {
+        add("Hex", parse_hex, |_| 95);
+    } else if is_hex_digits && !is_digits {
+        add("Hex", parse_hex, |bytes| 60 + solana_length_bonus(bytes));
+    } else if is_hex_digits {
+        // All digits: a 32 or 64 byte hash or key is more likely than a number that long
+        add("Hex", parse_hex, |bytes| match bytes.len() {
+            32 | 64 => 75,
+            _ => 40,
+        });
+    }
+    if is_digits {
+        add("Decimal U256", parse_u256, |_| 70);
+    }
+    if !is_list {
+        add("Base58", parse_base58, |bytes| {
+            50 + solana_length_bonus(bytes)
+        });
+    }
+    if input.contains(['+', '/', '=']) || input.starts_with("[\"") {
+        add("Base64", parse_base64, |_| 80);
+    } else if !is_list {
+        add("Base64", parse_base64, |_| 30);
+        add("Base64url", parse_base64_url, |_| 20);
+    }
+
+    // Drop implausible candidates (e.g. a 'keypair' that is not 64 bytes)
+    candidates.retain(|candidate| candidate.score > 0);
+    candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.score));
+    candidates
+}
+
+type ParseFn = fn(&str) -> Result<Vec<u8>, ParseError>;
+
+/// Display a single editable representation of the bytes, with a copy button,
+/// and a note (e.g. a warning if the representation does not fit the current bytes).
+/// Characters in 'error_span' are underlined, to point at a parse failure.
+/// Returns the parse result if the text was edited this frame.
+fn representation_row(
+    ui: &mut Ui,
+    label: &str,
+    text: &mut String,
+    notes: impl IntoIterator<Item = RichText>,
+    error_span: Option<Range<usize>>,
+    parse: impl FnOnce(&str) -> Result<Vec<u8>, ParseError>,
+) -> Option<Result<Vec<u8>, ParseError>> {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        let mut layouter = |ui: &Ui, text: &str, wrap_width: f32| {
+            let mut job = underlined_layout_job(ui, text, error_span.clone());
+            job.wrap.max_width = wrap_width;
+            ui.fonts(|f| f.layout_job(job))
+        };
+        let response = ui.add(TextEdit::singleline(text).layouter(&mut layouter));
+        if ui.button("Copy").clicked() {
+            ui.output_mut(|o| o.copied_text = text.clone());
+        }
+        for note in notes {
+            ui.label(note);
+        }
+        response.changed().then(|| parse(text))
+    })
+    .inner
+}
+
+/// Lay out text edit contents, underlining the characters in 'error_span'
+fn underlined_layout_job(ui: &Ui, text: &str, error_span: Option<Range<usize>>) -> LayoutJob {
+    let font_id = TextStyle::Body.resolve(ui.style());
+    let color = ui
+        .visuals()
+        .override_text_color
+        .unwrap_or_else(|| ui.visuals().widgets.inactive.text_color());
+    let normal = TextFormat::simple(font_id, color);
+    let error = TextFormat {
+        color: ui.visuals().error_fg_color,
+        underline: Stroke::new(2.0, ui.visuals().error_fg_color),
+        ..normal.clone()
+    };
+
+    let mut job = LayoutJob::default();
+    match error_span {
+        // The span may be stale if the text changed, so check it still fits
+        Some(span)
+            if span.start < span.end
+                && span.end <= text.len()
+                && text.is_char_boundary(span.start)
+                && text.is_char_boundary(span.end) =>
+        {
+            job.append(&text[..span.start], 0.0, normal.clone());
+            job.append(&text[span.clone()], 0.0, error);
+            job.append(&text[span.end..], 0.0, normal);
+        }
+        _ => job.append(text, 0.0, normal),
+    }
+    job
+}
+
+/// Why a representation failed to parse, and which characters are at fault
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub reason: String,
+    /// Byte range of the offending characters in the input, if they can be pinpointed
+    pub span: Option<Range<usize>>,
+}
+
+impl ParseError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            span: None,
+        }
+    }
+
+    fn at(reason: impl Into<String>, span: Range<usize>) -> Self {
+        Self {
+            reason: reason.into(),
+            span: Some(span),
+        }
+    }
+
+    /// Shift the span, for errors from parsing a slice of the input
+    fn offset(mut self, offset: usize) -> Self {
+        self.span = self.span.map(|span| span.start + offset..span.end + offset);
+        self
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.span {
+            Some(span) => write!(f, "{} (at position {})", self.reason, span.start),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+/// Span of the character starting at byte 'index' (a single byte if it is not a char boundary)
+fn char_span(input: &str, index: usize) -> Range<usize> {
+    let len = input
+        .get(index..)
+        .and_then(|rest| rest.chars().next())
+        .map_or(1, char::len_utf8);
+    index..index + len
+}
+
+/// Span of the last character of the input, for errors like 'odd length'
+fn last_char_span(input: &str) -> Option<Range<usize>> {
+    let (index, c) = input.char_indices().last()?;
+    Some(index..index + c.len_utf8())
+}
+
+/// Whitespace separated tokens, with their byte offsets in the input
+fn tokens_with_offsets(input: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (index, c) in input.char_indices() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(index),
+            (true, Some(token_start)) => {
+                tokens.push((token_start, &input[token_start..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(token_start) = start {
+        tokens.push((token_start, &input[token_start..]));
+    }
+    tokens
+}
+
 /// Allow parsing of different types of byte arrays:
 /// "[1, 2, 3]" -> vec![1, 2, 3]
 /// "1 2 3" -> vec![1, 2, 3]
 /// etc.
+/// Separators are replaced one-for-one, so offsets still point into the original input.
 fn cleanse_byte_list_input(input: &str) -> String {
-    input
-        .replace([',', ';', ':', '\t', '\n'], " ")
-        .replace(['[', ']'], "")
+    input.replace([',', ';', ':', '\t', '\n', '[', ']'], " ")
 }
 
-fn parse_byte_list_u8(input: &str) -> Result<Vec<u8>, String> {
+pub fn parse_byte_list_u8(input: &str) -> Result<Vec<u8>, ParseError> {
     let mut result: Vec<u8> = Vec::new();
-    for byte in cleanse_byte_list_input(input).split_whitespace() {
+    let input = cleanse_byte_list_input(input);
+    for (offset, byte) in tokens_with_offsets(&input) {
         match byte.parse::<u8>() {
             Ok(byte) => result.push(byte),
-            Err(_) => return Err(format!("Failed to parse byte: {}", byte)),
+            Err(_) => {
+                let reason = format!("Failed to parse byte: {}", byte);
+                return Err(ParseError::at(reason, offset..offset + byte.len()));
+            }
         }
     }
     Ok(result)
 }
 
-fn parse_byte_list_i8(input: &str) -> Result<Vec<u8>, String> {
+fn parse_byte_list_i8(input: &str) -> Result<Vec<u8>, ParseError> {
     let mut result: Vec<u8> = Vec::new();
-    for byte in cleanse_byte_list_input(input).split_whitespace() {
+    let input = cleanse_byte_list_input(input);
+    for (offset, byte) in tokens_with_offsets(&input) {
         match byte.parse::<i8>() {
             Ok(byte) => result.push(byte as u8),
-            Err(_) => return Err(format!("Failed to parse byte: {}", byte)),
+            Err(_) => {
+                let reason = format!("Failed to parse byte: {}", byte);
+                return Err(ParseError::at(reason, offset..offset + byte.len()));
+            }
         }
     }
     Ok(result)
 }
 
-fn parse_u256(input: &str) -> Result<Vec<u8>, String> {
+fn parse_byte_list_octal(input: &str) -> Result<Vec<u8>, ParseError> {
+    let mut result: Vec<u8> = Vec::new();
+    let input = cleanse_byte_list_input(input);
+    for (offset, byte) in tokens_with_offsets(&input) {
+        let digits = byte.strip_prefix("0o").unwrap_or(byte);
+        match u8::from_str_radix(digits, 8) {
+            Ok(byte) => result.push(byte),
+            Err(_) => {
+                let reason = format!("Failed to parse octal byte: {}", byte);
+                return Err(ParseError::at(reason, offset..offset + byte.len()));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Parse a binary string. Either:
+/// "00000001 00000010" -> vec![1, 2] (one group per byte)
+/// "0b100000010" -> vec![1, 2] (a single number, left padded to a whole byte)
+fn parse_binary(input: &str) -> Result<Vec<u8>, ParseError> {
+    // (span of the group in the input, its digits without separators)
+    let mut groups = Vec::new();
+    for (offset, token) in tokens_with_offsets(input) {
+        let (digits_offset, digits) = match token.strip_prefix("0b") {
+            Some(digits) => (offset + 2, digits),
+            None => (offset, token),
+        };
+        let invalid = digits
+            .char_indices()
+            .find(|(_, c)| !matches!(c, '0' | '1' | '_'));
+        if let Some((index, c)) = invalid {
+            let reason = format!("Failed to parse binary: invalid digit '{}'", c);
+            let span = char_span(digits, index);
+            return Err(ParseError::at(reason, span).offset(digits_offset));
+        }
+        groups.push((offset..offset + token.len(), digits.replace('_', "")));
+    }
+
+    let bytes: Vec<(Range<usize>, String)> = match groups.as_slice() {
+        [(span, single)] => {
+            let padding = "0".repeat((8 - single.len() % 8) % 8);
+            let padded: Vec<char> = format!("{padding}{single}").chars().collect();
+            padded
+                .chunks(8)
+                .map(|chunk| (span.clone(), chunk.iter().collect()))
+                .collect()
+        }
+        groups => groups.to_vec(),
+    };
+
+    let mut result: Vec<u8> = Vec::new();
+    for (span, byte) in bytes {
+        match u8::from_str_radix(&byte, 2) {
+            Ok(byte) => result.push(byte),
+            Err(_) => {
+                let reason = format!("Failed to parse binary byte: {}", byte);
+                return Err(ParseError::at(reason, span));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Error for a failed decimal parse, pointing at the first non-digit character.
+/// If all characters are digits, the value is too large and the whole input is at fault.
+fn decimal_error(name: &str, input: &str, e: impl std::fmt::Display) -> ParseError {
+    let reason = format!("Failed to parse {}: {}", name, e);
+    let invalid = input
+        .char_indices()
+        .find(|&(index, c)| !(c.is_ascii_digit() || (index == 0 && c == '-')));
+    match invalid {
+        Some((index, _)) => ParseError::at(reason, char_span(input, index)),
+        None if !input.is_empty() => ParseError::at(reason, 0..input.len()),
+        None => ParseError::new(reason),
+    }
+}
+
+fn parse_u256(input: &str) -> Result<Vec<u8>, ParseError> {
     match U256::from_dec_str(input) {
         Ok(u256) => {
             let mut result = vec![0; 4 * 8];
             u256.to_big_endian(&mut result);
             Ok(result)
         }
-        Err(e) => Err(format!("Failed to parse U256: {}", e)),
+        Err(e) => Err(decimal_error("U256", input, e)),
     }
 }
 
-fn parse_hex(input: &str) -> Result<Vec<u8>, String> {
-    // If it starts with 0x, remove it
-    let input = if let Some(i) = input.strip_prefix("0x") {
-        i
+fn parse_i256(input: &str) -> Result<Vec<u8>, ParseError> {
+    let start = input.len() - input.trim_start().len();
+    let input = input.trim();
+    let (negative, magnitude) = match input.strip_prefix('-') {
+        Some(magnitude) => (true, magnitude),
+        None => (false, input),
+    };
+    let magnitude =
+        U256::from_dec_str(magnitude).map_err(|e| decimal_error("I256", input, e).offset(start))?;
+
+    // Two's complement range is [-2^255, 2^255 - 1]
+    let limit = U256::one() << 255;
+    if (negative && magnitude > limit) || (!negative && magnitude >= limit) {
+        let reason = format!("Value is out of range for I256: {}", input);
+        return Err(ParseError::at(reason, start..start + input.len()));
+    }
+
+    let value = if negative {
+        (!magnitude).overflowing_add(U256::one()).0
     } else {
-        input
+        magnitude
+    };
+    let mut result = vec![0; 4 * 8];
+    value.to_big_endian(&mut result);
+    Ok(result)
+}
+
+/// Interpret big-endian bytes as a two's complement I256, sign-extending shorter inputs.
+/// None if the input is larger than 32 bytes.
+fn format_i256(input: &[u8]) -> Option<String> {
+    if input.len() > 4 * 8 {
+        return None;
+    }
+
+    let negative = input.first().is_some_and(|byte| byte & 0x80 != 0);
+    let mut extended = vec![if negative { 0xff } else { 0 }; 4 * 8 - input.len()];
+    extended.extend_from_slice(input);
+
+    let value = U256::from_big_endian(&extended);
+    if negative {
+        Some(format!("-{}", (!value).overflowing_add(U256::one()).0))
+    } else {
+        Some(value.to_string())
+    }
+}
+
+fn parse_u512(input: &str) -> Result<Vec<u8>, ParseError> {
+    match U512::from_dec_str(input) {
+        Ok(u512) => {
+            let mut result = vec![0; 8 * 8];
+            u512.to_big_endian(&mut result);
+            Ok(result)
+        }
+        Err(e) => Err(decimal_error("U512", input, e)),
+    }
+}
+
+fn parse_utf8(input: &str) -> Result<Vec<u8>, ParseError> {
+    Ok(input.as_bytes().to_vec())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum LiteralSyntax {
+    /// b"vault\x00"
+    Rust,
+    /// "vault\000"
+    C,
+    /// b'vault\x00'
+    Python,
+}
+
+fn format_escaped_literal(input: &[u8], syntax: LiteralSyntax) -> String {
+    let (prefix, quote) = match syntax {
+        LiteralSyntax::Rust => ("b", '"'),
+        LiteralSyntax::C => ("", '"'),
+        LiteralSyntax::Python => ("b", '\''),
+    };
+
+    let mut result = format!("{prefix}{quote}");
+    for &byte in input {
+        match byte {
+            b'\n' => result.push_str("\\n"),
+            b'\r' => result.push_str("\\r"),
+            b'\t' => result.push_str("\\t"),
+            b'\\' => result.push_str("\\\\"),
+            _ if byte as char == quote => {
+                result.push('\\');
+                result.push(quote);
+            }
+            0x20..=0x7e => result.push(byte as char),
+            // C hex escapes are not length limited, so use octal to avoid swallowing a following digit
+            _ if syntax == LiteralSyntax::C => result.push_str(&format!("\\{:03o}", byte)),
+            _ => result.push_str(&format!("\\x{:02x}", byte)),
+        }
+    }
+    result.push(quote);
+    result
+}
+
+/// Parse a Rust, C or Python bytes string literal. Adjacent literals are concatenated:
+/// b"vault\x00" -> vec![118, 97, 117, 108, 116, 0]
+/// "va" "ult\0" -> vec![118, 97, 117, 108, 116, 0]
+fn parse_escaped_literal(input: &str) -> Result<Vec<u8>, ParseError> {
+    let mut result = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let quote = match c {
+            'b' | 'B' => chars.next().map(|(_, c)| c),
+            _ => Some(c),
+        };
+        let quote = match quote {
+            Some(q @ ('"' | '\'')) => q,
+            _ => {
+                let reason = "Failed to parse literal: expected a quoted string";
+                return Err(ParseError::at(reason, char_span(input, start)));
+            }
+        };
+
+        loop {
+            let Some((index, c)) = chars.next() else {
+                let reason = "Failed to parse literal: missing closing quote";
+                return Err(ParseError::at(reason, start..input.len()));
+            };
+            if c == quote {
+                break;
+            }
+            if c != '\\' {
+                let mut buf = [0; 4];
+                result.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                continue;
+            }
+
+            let Some((_, escape)) = chars.next() else {
+                let reason = "Failed to parse literal: unfinished escape";
+                return Err(ParseError::at(reason, index..input.len()));
+            };
+            let byte = match escape {
+                'n' => Some(b'\n'),
+                'r' => Some(b'\r'),
+                't' => Some(b'\t'),
+                'a' => Some(0x07),
+                'b' => Some(0x08),
+                'f' => Some(0x0c),
+                'v' => Some(0x0b),
+                '\\' | '"' | '\'' | '?' => Some(escape as u8),
+                'x' => {
+                    let mut digits = String::new();
+                    while digits.len() < 2
+                        && chars.peek().is_some_and(|(_, c)| c.is_ascii_hexdigit())
+                    {
+                        digits.extend(chars.next().map(|(_, c)| c));
+                    }
+                    u8::from_str_radix(&digits, 16).ok()
+                }
+                '0'..='7' => {
+                    let mut digits = escape.to_string();
+                    while digits.len() < 3
+                        && chars.peek().is_some_and(|(_, c)| ('0'..='7').contains(c))
+                    {
+                        digits.extend(chars.next().map(|(_, c)| c));
+                    }
+                    u8::from_str_radix(&digits, 8).ok()
+                }
+                _ => None,
+            };
+
+            let end = chars.peek().map_or(input.len(), |(end, _)| *end);
+            match byte {
+                Some(byte) => result.push(byte),
+                None => {
+                    let reason =
+                        format!("Failed to parse literal: bad escape {}", &input[index..end]);
+                    return Err(ParseError::at(reason, index..end));
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+pub fn parse_hex(input: &str) -> Result<Vec<u8>, ParseError> {
+    // If it starts with 0x, remove it
+    let (offset, digits) = match input.strip_prefix("0x") {
+        Some(digits) => (2, digits),
+        None => (0, input),
     };
 
     // Verify that the input is valid hex
-    match hex::decode(input) {
+    match hex::decode(digits) {
         Ok(s) => Ok(s),
-        Err(e) => Err(format!("Failed to parse hex: {}", e)),
+        Err(hex::FromHexError::InvalidHexCharacter { c, index }) => {
+            let reason = format!("Failed to parse hex: invalid character '{}'", c);
+            Err(ParseError::at(reason, char_span(digits, index)).offset(offset))
+        }
+        Err(e) => {
+            let reason = format!("Failed to parse hex: {}", e);
+            match last_char_span(input) {
+                Some(span) => Err(ParseError::at(reason, span)),
+                None => Err(ParseError::new(reason)),
+            }
+        }
     }
 }
 
-fn parse_base58(input: &str) -> Result<Vec<u8>, String> {
+pub fn parse_base58(input: &str) -> Result<Vec<u8>, ParseError> {
     // Verify that the input is valid base58
     match bs58::decode(input).into_vec() {
         Ok(s) => Ok(s),
-        Err(e) => Err(format!("Failed to parse base58: {:?}", e)),
+        Err(bs58::decode::Error::InvalidCharacter { character, index }) => {
+            let reason = format!("Failed to parse base58: invalid character '{}'", character);
+            Err(ParseError::at(reason, char_span(input, index)))
+        }
+        Err(bs58::decode::Error::NonAsciiCharacter { index }) => {
+            let reason = "Failed to parse base58: non-ASCII character";
+            Err(ParseError::at(reason, char_span(input, index)))
+        }
+        Err(e) => Err(ParseError::new(format!("Failed to parse base58: {}", e))),
+    }
+}
+
+// Padding is optional when parsing, so the same parser can back the padded and unpadded rows
+const BASE64_STANDARD_LENIENT: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+const BASE64_URL_SAFE_LENIENT: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Allow pasting the RPC 'data' field directly:
+/// "AQID" -> "AQID"
+/// "[\"AQID\", \"base64\"]" -> "AQID"
+/// Also returns the byte offset of each kept character in the original input.
+fn cleanse_base64_input(input: &str) -> (String, Vec<usize>) {
+    let trimmed = input.trim();
+    let data = match serde_json::from_str::<Vec<String>>(trimmed) {
+        Ok(data) if !data.is_empty() => data[0].clone(),
+        _ => trimmed.trim_matches('"').to_string(),
+    };
+    let start = input.find(data.as_str()).unwrap_or(0);
+    data.char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(index, c)| (c, start + index))
+        .unzip()
+}
+
+fn decode_base64(name: &str, engine: &GeneralPurpose, input: &str) -> Result<Vec<u8>, ParseError> {
+    let (data, offsets) = cleanse_base64_input(input);
+    let span = |offset: usize| offsets.get(offset).map(|&index| char_span(input, index));
+    let (reason, span) = match engine.decode(&data) {
+        Ok(s) => return Ok(s),
+        Err(base64::DecodeError::InvalidByte(offset, byte))
+        | Err(base64::DecodeError::InvalidLastSymbol(offset, byte)) => (
+            format!("invalid character '{}'", byte as char),
+            span(offset),
+        ),
+        Err(e) => (e.to_string(), offsets.len().checked_sub(1).and_then(span)),
+    };
+    let reason = format!("Failed to parse {}: {}", name, reason);
+    match span {
+        Some(span) => Err(ParseError::at(reason, span)),
+        None => Err(ParseError::new(reason)),
+    }
+}
+
+pub fn parse_base64(input: &str) -> Result<Vec<u8>, ParseError> {
+    decode_base64("base64", &BASE64_STANDARD_LENIENT, input)
+}
+
+fn parse_base64_url(input: &str) -> Result<Vec<u8>, ParseError> {
+    decode_base64("base64url", &BASE64_URL_SAFE_LENIENT, input)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parse_base64_variants() {
+        let bytes = vec![0xfb, 0xff, 0x01];
+        assert_eq!(super::parse_base64("+/8B").unwrap(), bytes);
+        assert_eq!(super::parse_base64_url("-_8B").unwrap(), bytes);
+
+        // Padding is optional, and the RPC data field can be pasted as-is
+        assert_eq!(super::parse_base64("AQ==").unwrap(), vec![1]);
+        assert_eq!(super::parse_base64("AQ").unwrap(), vec![1]);
+        assert_eq!(
+            super::parse_base64("[\"AQID\", \"base64\"]").unwrap(),
+            vec![1, 2, 3]
+        );
+        assert!(super::parse_base64("-_8B").is_err());
+    }
+
+    #[test]
+    fn parse_binary_and_octal() {
+        assert_eq!(
+            super::parse_binary("00000001 11111111").unwrap(),
+            vec![1, 255]
+        );
+        assert_eq!(super::parse_binary("0b1_00000010").unwrap(), vec![1, 2]);
+        assert!(super::parse_binary("000000012").is_err());
+        assert!(super::parse_binary("100000000 1").is_err());
+
+        assert_eq!(
+            super::parse_byte_list_octal("001 0o377").unwrap(),
+            vec![1, 255]
+        );
+        assert!(super::parse_byte_list_octal("400").is_err());
+    }
+
+    #[test]
+    fn escaped_literal_round_trip() {
+        use super::{format_escaped_literal, parse_escaped_literal, LiteralSyntax};
+
+        let bytes = b"vault\x00\"'\\\n1".to_vec();
+        assert_eq!(
+            format_escaped_literal(&bytes, LiteralSyntax::Rust),
+            r#"b"vault\x00\"'\\\n1""#
+        );
+        assert_eq!(
+            format_escaped_literal(&bytes, LiteralSyntax::C),
+            r#""vault\000\"'\\\n1""#
+        );
+        assert_eq!(
+            format_escaped_literal(&bytes, LiteralSyntax::Python),
+            r#"b'vault\x00"\'\\\n1'"#
+        );
+        for syntax in [LiteralSyntax::Rust, LiteralSyntax::C, LiteralSyntax::Python] {
+            let literal = format_escaped_literal(&bytes, syntax);
+            assert_eq!(parse_escaped_literal(&literal).unwrap(), bytes);
+        }
+
+        assert_eq!(
+            parse_escaped_literal(r#""va" "ult\0""#).unwrap(),
+            b"vault\0"
+        );
+        assert!(parse_escaped_literal("vault").is_err());
+        assert!(parse_escaped_literal(r#"b"vault"#).is_err());
+    }
+
+    #[test]
+    fn code_literal_round_trip() {
+        let bytes = vec![1, 2, 3];
+        let pubkey = super::parse_base58(crate::WSOL_ACCOUNT).unwrap();
+
+        for literal in super::CODE_LITERALS {
+            for input in [&bytes, &pubkey] {
+                if let Some(formatted) = literal.format(input) {
+                    assert_eq!(&super::parse_code_literal(&formatted).unwrap(), input);
+                }
+            }
+        }
+
+        assert_eq!(
+            super::CodeLiteral::RustArray.format(&bytes).unwrap(),
+            "[u8; 3] = [1, 2, 3]"
+        );
+        assert_eq!(
+            super::CodeLiteral::GoByteSlice.format(&bytes).unwrap(),
+            "[]byte{1, 2, 3}"
+        );
+        assert!(super::CodeLiteral::RustPubkeyMacro.format(&bytes).is_none());
+    }
+
+    #[test]
+    fn classify_pasted_input() {
+        let best = |input: &str| super::classify_input(input).remove(0).format;
+
+        assert_eq!(best(crate::WSOL_ACCOUNT), "Base58");
+        assert_eq!(best("0x0102"), "Hex");
+        assert_eq!(best("deadbeef"), "Hex");
+        assert_eq!(best("[1, 2, 3]"), "Byte list");
+        assert_eq!(best("12345"), "Decimal U256");
+        assert_eq!(best(&"0123456789".repeat(7)[..64]), "Hex");
+        assert_eq!(best(&"12".repeat(64)), "Hex");
+        assert_eq!(best(&"1".repeat(62)), "Decimal U256");
+        assert_eq!(best("AQID+w=="), "Base64");
+        assert_eq!(best("[\"AQID\", \"base64\"]"), "Base64");
+
+        let keypair = format!("[{}]", vec!["1"; 64].join(","));
+        assert_eq!(best(&keypair), "Solana keypair");
+        assert!(super::classify_input("not valid!").is_empty());
+    }
+
+    #[test]
+    fn parse_error_spans() {
+        let span = |result: Result<Vec<u8>, super::ParseError>| result.unwrap_err().span;
+
+        let mut base58 = crate::WSOL_ACCOUNT.to_string();
+        base58.replace_range(10..11, "l");
+        assert_eq!(span(super::parse_base58(&base58)), Some(10..11));
+        assert_eq!(span(super::parse_hex("0x01zz")), Some(4..5));
+        assert_eq!(span(super::parse_hex("0x012")), Some(4..5));
+        assert_eq!(span(super::parse_byte_list_u8("[1, 300, 2]")), Some(4..7));
+        assert_eq!(span(super::parse_binary("0b0102")), Some(5..6));
+        assert_eq!(span(super::parse_u256("12a4")), Some(2..3));
+        assert_eq!(
+            span(super::parse_base64("[\"AQ!D\", \"base64\"]")),
+            Some(4..5)
+        );
+        assert_eq!(span(super::parse_escaped_literal(r#"b"a\q""#)), Some(3..5));
+        assert_eq!(
+            span(super::parse_code_literal("[]byte{1, 2, x}")),
+            Some(13..14)
+        );
+        assert_eq!(
+            span(super::INTEGER_KINDS[1].parse(" 70000", true)),
+            Some(1..6)
+        );
+    }
+
+    #[test]
+    fn i256_round_trip() {
+        let minus_one = super::parse_i256("-1").unwrap();
+        assert_eq!(minus_one, vec![0xff; 32]);
+        assert_eq!(super::format_i256(&minus_one).unwrap(), "-1");
+        assert_eq!(super::format_i256(&[0x80]).unwrap(), "-128");
+        assert_eq!(super::format_i256(&[0x7f]).unwrap(), "127");
+
+        let min = "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+        let bytes = super::parse_i256(min).unwrap();
+        assert_eq!(super::format_i256(&bytes).unwrap(), min);
+        assert!(super::parse_i256(&min[1..]).is_err());
+    }
+
+    #[test]
+    fn integer_round_trip() {
+        let [u8_kind, u16_kind, .., i64_kind, _] = &super::INTEGER_KINDS;
+
+        assert_eq!(u16_kind.parse("258", true).unwrap(), vec![2, 1]);
+        assert_eq!(u16_kind.parse("258", false).unwrap(), vec![1, 2]);
+        assert_eq!(u16_kind.render(&[2, 1], true).unwrap(), "258");
+        assert!(u16_kind.render(&[2, 1, 0], true).is_none());
+        assert!(u8_kind.parse("256", true).is_err());
+
+        let bytes = i64_kind.parse("-2", true).unwrap();
+        assert_eq!(bytes, vec![0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(i64_kind.render(&bytes, true).unwrap(), "-2");
+        assert_eq!(
+            i64_kind.render(&bytes, false).unwrap(),
+            "-72057594037927937"
+        );
     }
 }
@@ -0,0 +1,699 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use egui::*;
+use serde::{Deserialize, Serialize};
+
+use super::base58_bytes::{parse_hex, BaseBytesConverter, IntegerKind, INTEGER_KINDS};
+use super::solana::pubkey_from_base58;
+
+/// Named fields of a struct or enum variant, in order
+pub type Fields = Vec<(String, BorshType)>;
+
+/// A Borsh type, written in the schema as Rust-like syntax:
+/// `u8`..`i128`, `bool`, `Pubkey`, `String`, `Vec<T>`, `Option<T>`, `[T; N]`, `{ name: T, .. }`
+/// and `enum { Unit, WithFields { name: T, .. }, .. }`
+#[derive(Clone)]
+pub enum BorshType {
+    Integer(&'static IntegerKind),
+    Bool,
+    Pubkey,
+    String,
+    Vec(Box<BorshType>),
+    Option(Box<BorshType>),
+    Array(Box<BorshType>, usize),
+    Struct(Fields),
+    /// Variants and their fields, selected by a u8 tag
+    Enum(Vec<(String, Fields)>),
+}
+
+impl BorshType {
+    /// `Vec<u8>` and `[u8; N]` are shown and edited as a single hex value
+    fn is_byte_sequence(&self) -> bool {
+        let element = match self {
+            BorshType::Vec(element) | BorshType::Array(element, _) => element,
+            _ => return false,
+        };
+        matches!(**element, BorshType::Integer(kind) if kind.label == "u8")
+    }
+
+    /// Empty structs, and arrays of them, take no bytes
+    fn is_zero_sized(&self) -> bool {
+        match self {
+            BorshType::Struct(fields) => fields.iter().all(|(_, ty)| ty.is_zero_sized()),
+            BorshType::Array(element, len) => *len == 0 || element.is_zero_sized(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for BorshType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BorshType::Integer(kind) => write!(f, "{}", kind.label),
+            BorshType::Bool => write!(f, "bool"),
+            BorshType::Pubkey => write!(f, "Pubkey"),
+            BorshType::String => write!(f, "String"),
+            BorshType::Vec(element) => write!(f, "Vec<{}>", element),
+            BorshType::Option(inner) => write!(f, "Option<{}>", inner),
+            BorshType::Array(element, len) => write!(f, "[{}; {}]", element, len),
+            BorshType::Struct(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(name, ty)| format!("{}: {}", name, ty))
+                    .collect();
+                write!(f, "{{ {} }}", fields.join(", "))
+            }
+            BorshType::Enum(variants) => {
+                let variants: Vec<String> = variants
+                    .iter()
+                    .map(|(name, fields)| match fields.is_empty() {
+                        true => name.clone(),
+                        false => format!("{} {}", name, BorshType::Struct(fields.clone())),
+                    })
+                    .collect();
+                write!(f, "enum {{ {} }}", variants.join(", "))
+            }
+        }
+    }
+}
+
+/// A layout as it is written in the schema editor: one `name: Type` field per line
+pub fn format_layout(fields: &[(String, BorshType)]) -> String {
+    fields
+        .iter()
+        .map(|(name, ty)| format!("{}: {}", name, ty))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(usize),
+    Punct(char),
+    Newline,
+}
+
+struct SchemaParser<'a> {
+    chars: Peekable<CharIndices<'a>>,
+    peeked: Option<Token>,
+}
+
+/// Parse a layout: fields separated by commas or newlines
+pub fn parse_layout(input: &str) -> Result<Fields, String> {
+    let mut parser = SchemaParser {
+        chars: input.char_indices().peekable(),
+        peeked: None,
+    };
+    let fields = parser.fields()?;
+    match parser.next_significant()? {
+        None => Ok(fields),
+        Some(token) => Err(format!("Unexpected {:?} after the last field", token)),
+    }
+}
+
+impl SchemaParser<'_> {
+    fn lex(&mut self) -> Result<Option<Token>, String> {
+        // Newlines separate fields, other whitespace is insignificant
+        while self
+            .chars
+            .next_if(|(_, c)| c.is_whitespace() && *c != '\n')
+            .is_some()
+        {}
+        let Some((position, c)) = self.chars.next() else {
+            return Ok(None);
+        };
+        let token = match c {
+            '\n' => Token::Newline,
+            '<' | '>' | '[' | ']' | '{' | '}' | ';' | ':' | ',' => Token::Punct(c),
+            c if c.is_ascii_digit() => {
+                let mut number = c.to_string();
+                while let Some((_, c)) = self.chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                    number.push(c);
+                }
+                Token::Number(number.parse().map_err(|e| format!("{}: {}", number, e))?)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut ident = c.to_string();
+                while let Some((_, c)) = self
+                    .chars
+                    .next_if(|(_, c)| c.is_alphanumeric() || *c == '_')
+                {
+                    ident.push(c);
+                }
+                Token::Ident(ident)
+            }
+            c => return Err(format!("Unexpected '{}' at position {}", c, position)),
+        };
+        Ok(Some(token))
+    }
+
+    fn peek(&mut self) -> Result<Option<&Token>, String> {
+        if self.peeked.is_none() {
+            self.peeked = self.lex()?;
+        }
+        Ok(self.peeked.as_ref())
+    }
+
+    fn next(&mut self) -> Result<Option<Token>, String> {
+        self.peek()?;
+        Ok(self.peeked.take())
+    }
+
+    /// The next token, skipping newlines. Types may be split across lines.
+    fn next_significant(&mut self) -> Result<Option<Token>, String> {
+        loop {
+            match self.next()? {
+                Some(Token::Newline) => continue,
+                token => return Ok(token),
+            }
+        }
+    }
+
+    fn expect(&mut self, punct: char) -> Result<(), String> {
+        match self.next_significant()? {
+            Some(Token::Punct(c)) if c == punct => Ok(()),
+            token => Err(format!("Expected '{}', got {:?}", punct, token)),
+        }
+    }
+
+    fn fields(&mut self) -> Result<Fields, String> {
+        let mut fields = Vec::new();
+        loop {
+            // Any number of separators between fields
+            while matches!(self.peek()?, Some(Token::Newline | Token::Punct(','))) {
+                self.next()?;
+            }
+            // Tuple struct fields are named by their index
+            let name = match self.peek()? {
+                Some(Token::Ident(_) | Token::Number(_)) => match self.next()? {
+                    Some(Token::Ident(name)) => name,
+                    Some(Token::Number(index)) => index.to_string(),
+                    _ => unreachable!(),
+                },
+                _ => return Ok(fields),
+            };
+            self.expect(':')?;
+            let ty = self.ty().map_err(|e| format!("Field {}: {}", name, e))?;
+            fields.push((name, ty));
+
+            match self.peek()? {
+                None | Some(Token::Newline | Token::Punct(',' | '}')) => {}
+                Some(token) => return Err(format!("Expected a new field, got {:?}", token)),
+            }
+        }
+    }
+
+    fn ty(&mut self) -> Result<BorshType, String> {
+        match self.next_significant()? {
+            Some(Token::Punct('[')) => {
+                let element = self.ty()?;
+                self.expect(';')?;
+                let len = match self.next_significant()? {
+                    Some(Token::Number(len)) => len,
+                    token => return Err(format!("Expected an array length, got {:?}", token)),
+                };
+                self.expect(']')?;
+                if element.is_zero_sized() {
+                    return Err(format!("Array elements take no bytes: {}", element));
+                }
+                Ok(BorshType::Array(Box::new(element), len))
+            }
+            Some(Token::Punct('{')) => {
+                let fields = self.fields()?;
+                self.expect('}')?;
+                Ok(BorshType::Struct(fields))
+            }
+            Some(Token::Ident(name)) => {
+                if let Some(kind) = INTEGER_KINDS.iter().find(|kind| kind.label == name) {
+                    return Ok(BorshType::Integer(kind));
+                }
+                match name.as_str() {
+                    "bool" => Ok(BorshType::Bool),
+                    "Pubkey" | "pubkey" | "publicKey" => Ok(BorshType::Pubkey),
+                    "String" | "string" => Ok(BorshType::String),
+                    "enum" => {
+                        self.expect('{')?;
+                        let variants = self.variants()?;
+                        self.expect('}')?;
+                        Ok(BorshType::Enum(variants))
+                    }
+                    "Vec" | "Option" => {
+                        self.expect('<')?;
+                        let inner = Box::new(self.ty()?);
+                        self.expect('>')?;
+                        if name == "Vec" && inner.is_zero_sized() {
+                            return Err(format!("Vec elements take no bytes: {}", inner));
+                        }
+                        Ok(match name.as_str() {
+                            "Vec" => BorshType::Vec(inner),
+                            _ => BorshType::Option(inner),
+                        })
+                    }
+                    _ => Err(format!("Unknown type: {}", name)),
+                }
+            }
+            token => Err(format!("Expected a type, got {:?}", token)),
+        }
+    }
+
+    fn variants(&mut self) -> Result<Vec<(String, Fields)>, String> {
+        let mut variants = Vec::new();
+        loop {
+            while matches!(self.peek()?, Some(Token::Newline | Token::Punct(','))) {
+                self.next()?;
+            }
+            let name = match self.peek()? {
+                Some(Token::Ident(_)) => match self.next()? {
+                    Some(Token::Ident(name)) => name,
+                    _ => unreachable!(),
+                },
+                _ => return Ok(variants),
+            };
+            let fields = match self.peek()? {
+                Some(Token::Punct('{')) => {
+                    self.next()?;
+                    let fields = self.fields()?;
+                    self.expect('}')?;
+                    fields
+                }
+                _ => Vec::new(),
+            };
+            variants.push((name, fields));
+        }
+    }
+}
+
+/// A decoded value and the bytes it was read from
+pub struct DecodedField {
+    pub name: String,
+    pub ty: BorshType,
+    pub offset: usize,
+    pub len: usize,
+    pub value: DecodedValue,
+}
+
+pub enum DecodedValue {
+    /// A primitive, as editable text
+    Leaf { text: String, error: Option<String> },
+    /// Option::None
+    None,
+    /// Struct fields, sequence elements, the value of Option::Some or the variant of an enum
+    Children(Vec<DecodedField>),
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize, name: &str) -> Result<&'a [u8], String> {
+        let end = self.offset.checked_add(len);
+        let bytes = end
+            .and_then(|end| self.bytes.get(self.offset..end))
+            .ok_or_else(|| {
+                format!(
+                    "Unexpected end of data reading {} at byte {}: needs {} bytes, have {}",
+                    name,
+                    self.offset,
+                    len,
+                    self.bytes.len() - self.offset
+                )
+            })?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn length_prefix(&mut self, name: &str) -> Result<usize, String> {
+        let bytes = self.take(4, name)?;
+        let len = u32::from_le_bytes(bytes.try_into().unwrap()) as usize;
+        // Layouts have no zero-sized elements, so a longer length means the layout is wrong
+        if len > self.bytes.len() - self.offset {
+            return Err(format!(
+                "Length of {} is {}, more than the bytes left",
+                name, len
+            ));
+        }
+        Ok(len)
+    }
+
+    fn field(&mut self, name: String, ty: &BorshType) -> Result<DecodedField, String> {
+        let offset = self.offset;
+        let value = match ty {
+            _ if ty.is_byte_sequence() => {
+                let len = match ty {
+                    BorshType::Array(_, len) => *len,
+                    _ => self.length_prefix(&name)?,
+                };
+                leaf(hex::encode(self.take(len, &name)?))
+            }
+            BorshType::Integer(kind) => {
+                leaf(kind.render(self.take(kind.width, &name)?, true).unwrap())
+            }
+            BorshType::Bool => match self.take(1, &name)?[0] {
+                0 => leaf("false".to_string()),
+                1 => leaf("true".to_string()),
+                byte => {
+                    return Err(format!(
+                        "Invalid bool for {} at byte {}: {}",
+                        name, offset, byte
+                    ))
+                }
+            },
+            BorshType::Pubkey => leaf(bs58::encode(self.take(32, &name)?).into_string()),
+            BorshType::String => {
+                let len = self.length_prefix(&name)?;
+                let bytes = self.take(len, &name)?;
+                let text = std::str::from_utf8(bytes)
+                    .map_err(|e| format!("Invalid UTF-8 in {}: {}", name, e))?;
+                leaf(text.to_string())
+            }
+            BorshType::Option(inner) => match self.take(1, &name)?[0] {
+                0 => DecodedValue::None,
+                1 => DecodedValue::Children(vec![self.field("Some".to_string(), inner)?]),
+                byte => {
+                    return Err(format!(
+                        "Invalid Option tag for {} at byte {}: {}",
+                        name, offset, byte
+                    ))
+                }
+            },
+            BorshType::Vec(element) => {
+                let len = self.length_prefix(&name)?;
+                let elements = (0..len)
+                    .map(|index| self.field(format!("[{}]", index), element))
+                    .collect::<Result<_, _>>()?;
+                DecodedValue::Children(elements)
+            }
+            BorshType::Array(element, len) => {
+                let elements = (0..*len)
+                    .map(|index| self.field(format!("[{}]", index), element))
+                    .collect::<Result<_, _>>()?;
+                DecodedValue::Children(elements)
+            }
+            BorshType::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, ty)| self.field(name.clone(), ty))
+                    .collect::<Result<_, _>>()?;
+                DecodedValue::Children(fields)
+            }
+            BorshType::Enum(variants) => {
+                let tag = self.take(1, &name)?[0];
+                let (variant, fields) = variants.get(tag as usize).ok_or_else(|| {
+                    format!("Invalid variant for {} at byte {}: {}", name, offset, tag)
+                })?;
+                let fields = BorshType::Struct(fields.clone());
+                DecodedValue::Children(vec![self.field(variant.clone(), &fields)?])
+            }
+        };
+
+        Ok(DecodedField {
+            name,
+            ty: ty.clone(),
+            offset,
+            len: self.offset - offset,
+            value,
+        })
+    }
+}
+
+fn leaf(text: String) -> DecodedValue {
+    DecodedValue::Leaf { text, error: None }
+}
+
+/// Decode the bytes as a layout. Returns the fields and the number of bytes read.
+pub fn decode_layout(
+    fields: &[(String, BorshType)],
+    bytes: &[u8],
+) -> Result<(Vec<DecodedField>, usize), String> {
+    let mut decoder = Decoder { bytes, offset: 0 };
+    let fields = fields
+        .iter()
+        .map(|(name, ty)| decoder.field(name.clone(), ty))
+        .collect::<Result<_, _>>()?;
+    Ok((fields, decoder.offset))
+}
+
+/// Encode the text of a leaf, in the format it is decoded as
+pub fn encode_leaf(ty: &BorshType, text: &str) -> Result<Vec<u8>, String> {
+    let with_length = |bytes: Vec<u8>| {
+        let mut encoded = (bytes.len() as u32).to_le_bytes().to_vec();
+        encoded.extend(bytes);
+        encoded
+    };
+
+    match ty {
+        BorshType::Array(_, len) if ty.is_byte_sequence() => {
+            let bytes = parse_hex(text).map_err(|e| e.to_string())?;
+            if bytes.len() != *len {
+                return Err(format!("Needs {} bytes, have {}", len, bytes.len()));
+            }
+            Ok(bytes)
+        }
+        BorshType::Vec(_) if ty.is_byte_sequence() => {
+            Ok(with_length(parse_hex(text).map_err(|e| e.to_string())?))
+        }
+        BorshType::Integer(kind) => kind.parse(text, true).map_err(|e| e.to_string()),
+        BorshType::Bool => match text.trim() {
+            "false" => Ok(vec![0]),
+            "true" => Ok(vec![1]),
+            _ => Err("Expected true or false".to_string()),
+        },
+        BorshType::Pubkey => pubkey_from_base58(text).map(|pubkey| pubkey.to_vec()),
+        BorshType::String => Ok(with_length(text.as_bytes().to_vec())),
+        _ => Err(format!("{} is not edited directly", ty)),
+    }
+}
+
+/// A layout definition and the name it was saved under
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SavedLayout {
+    pub name: String,
+    pub definition: String,
+}
+
+/// Decodes the byte converter's buffer with a Borsh layout, and re-encodes edited fields.
+/// Only the saved layouts are stored.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BorshDecoder {
+    pub layouts: Vec<SavedLayout>,
+
+    #[serde(skip)]
+    pub display_name: String,
+    #[serde(skip)]
+    pub display_definition: String,
+
+    #[serde(skip)]
+    pub display_error: Option<String>,
+    #[serde(skip)]
+    decoded: Option<(Vec<DecodedField>, usize)>,
+    /// The bytes and definition `decoded` was made from
+    #[serde(skip)]
+    decoded_from: Option<(Vec<u8>, String)>,
+}
+
+impl BorshDecoder {
+    pub fn ui(&mut self, ui: &mut Ui, byte_converter: &mut BaseBytesConverter) {
+        ui.horizontal(|ui| {
+            ComboBox::from_id_source("borsh_layout")
+                .selected_text("Saved layouts")
+                .show_ui(ui, |ui| {
+                    for layout in &self.layouts {
+                        if ui.selectable_label(false, &layout.name).clicked() {
+                            self.display_name = layout.name.clone();
+                            self.display_definition = layout.definition.clone();
+                        }
+                    }
+                });
+            ui.label("Name: ");
+            ui.text_edit_singleline(&mut self.display_name);
+            if ui.button("Save").clicked() && !self.display_name.is_empty() {
+                self.save_layout();
+            }
+            if ui.button("Delete").clicked() {
+                self.layouts
+                    .retain(|layout| layout.name != self.display_name);
+            }
+        });
+
+        ui.add(
+            TextEdit::multiline(&mut self.display_definition)
+                .code_editor()
+                .desired_rows(4)
+                .hint_text("authority: Pubkey\namount: u64\nname: String\nitems: Vec<{ id: u8, flag: bool }>"),
+        );
+
+        self.decode(&byte_converter.bytes);
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+        let Some((fields, read)) = &mut self.decoded else {
+            return;
+        };
+
+        let mut edited = None;
+        for field in fields.iter_mut() {
+            field_ui(ui, field, "", &mut edited);
+        }
+        if *read < byte_converter.bytes.len() {
+            ui.label(format!(
+                "{} trailing bytes after the layout, from byte {}",
+                byte_converter.bytes.len() - *read,
+                read
+            ));
+        }
+
+        // Splice the re-encoded field into the buffer. The next frame decodes the new bytes.
+        if let Some((range, encoded)) = edited {
+            let mut bytes = byte_converter.bytes.clone();
+            bytes.splice(range, encoded);
+            byte_converter.update_texts(bytes);
+        }
+    }
+
+    /// Show a layout in the editor, replacing the current definition
+    pub fn load_layout(&mut self, name: String, definition: String) {
+        self.display_name = name;
+        self.display_definition = definition;
+    }
+
+    fn save_layout(&mut self) {
+        let layout = SavedLayout {
+            name: self.display_name.clone(),
+            definition: self.display_definition.clone(),
+        };
+        match self.layouts.iter_mut().find(|l| l.name == layout.name) {
+            Some(existing) => *existing = layout,
+            None => self.layouts.push(layout),
+        }
+    }
+
+    /// Decode again if the bytes or the definition changed since the last decode
+    fn decode(&mut self, bytes: &[u8]) {
+        if let Some((decoded_bytes, decoded_definition)) = &self.decoded_from {
+            if decoded_bytes == bytes && *decoded_definition == self.display_definition {
+                return;
+            }
+        }
+        self.decoded_from = Some((bytes.to_vec(), self.display_definition.clone()));
+        self.decoded = None;
+        self.display_error = None;
+
+        if self.display_definition.trim().is_empty() {
+            return;
+        }
+        match parse_layout(&self.display_definition)
+            .and_then(|fields| decode_layout(&fields, bytes))
+        {
+            Ok(decoded) => self.decoded = Some(decoded),
+            Err(e) => self.display_error = Some(e),
+        }
+    }
+}
+
+/// A row per field: its byte range, name and type, and an editor for primitives.
+/// 'parent' is the path of the enclosing fields, which keeps header ids unique.
+fn field_ui(
+    ui: &mut Ui,
+    field: &mut DecodedField,
+    parent: &str,
+    edited: &mut Option<(std::ops::Range<usize>, Vec<u8>)>,
+) {
+    let path = format!("{}/{}", parent, field.name);
+    let range = field.offset..field.offset + field.len;
+    let header = format!(
+        "{:>5}..{:<5} {}: {}",
+        range.start, range.end, field.name, field.ty
+    );
+    match &mut field.value {
+        DecodedValue::Leaf { text, error } => {
+            ui.horizontal(|ui| {
+                ui.monospace(header);
+                if ui.text_edit_singleline(text).changed() {
+                    match encode_leaf(&field.ty, text) {
+                        Ok(encoded) => *edited = Some((range, encoded)),
+                        Err(e) => *error = Some(e),
+                    }
+                }
+                if let Some(error) = error {
+                    ui.colored_label(Color32::RED, error.as_str());
+                }
+            });
+        }
+        DecodedValue::None => {
+            ui.monospace(format!("{} = None", header));
+        }
+        DecodedValue::Children(children) => {
+            CollapsingHeader::new(RichText::new(header).monospace())
+                .id_source(ui.id().with(("borsh_field", &path, field.offset)))
+                .default_open(true)
+                .show(ui, |ui| {
+                    for child in children {
+                        field_ui(ui, child, &path, edited);
+                    }
+                });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn layout_round_trip() {
+        let definition = "authority: Pubkey\nseeds: Vec<[u8; 2]>, name: String\n\
+                          inner: Option<{ flag: bool, amount: i64 }>\n\
+                          state: enum { Closed, Open { 0: u8 } }";
+        let fields = super::parse_layout(definition).unwrap();
+        assert_eq!(
+            super::format_layout(&fields),
+            "authority: Pubkey\nseeds: Vec<[u8; 2]>\nname: String\n\
+             inner: Option<{ flag: bool, amount: i64 }>\n\
+             state: enum { Closed, Open { 0: u8 } }"
+        );
+
+        let mut bytes = vec![7; 32];
+        bytes.extend([1, 0, 0, 0, 0xab, 0xcd]);
+        bytes.extend([2, 0, 0, 0, b'h', b'i']);
+        bytes.extend([1, 1]);
+        bytes.extend((-5i64).to_le_bytes());
+        bytes.extend([1, 9]);
+        bytes.push(0xff);
+
+        let (decoded, read) = super::decode_layout(&fields, &bytes).unwrap();
+        assert_eq!(read, bytes.len() - 1);
+        assert_eq!((decoded[2].offset, decoded[2].len), (38, 6));
+        let super::DecodedValue::Children(inner) = &decoded[3].value else {
+            panic!("Option::Some has a child");
+        };
+        let super::DecodedValue::Children(inner) = &inner[0].value else {
+            panic!("Struct has children");
+        };
+        let super::DecodedValue::Leaf { text, .. } = &inner[1].value else {
+            panic!("i64 is a leaf");
+        };
+        assert_eq!(text, "-5");
+        assert_eq!(
+            super::encode_leaf(&inner[1].ty, text).unwrap(),
+            &bytes[46..54]
+        );
+
+        assert!(super::decode_layout(&fields, &bytes[..40]).is_err());
+        assert!(super::parse_layout("a: Vec<u8").is_err());
+
+        let overflow = super::parse_layout("a: u8, b: [u8; 18446744073709551615]").unwrap();
+        assert!(super::decode_layout(&overflow, &[1, 2]).is_err());
+    }
+
+    #[test]
+    fn zero_sized_elements() {
+        assert!(super::parse_layout("a: [{}; 1000000000]").is_err());
+        assert!(super::parse_layout("a: Vec<{ b: [u8; 0] }>").is_err());
+        assert!(super::parse_layout("a: Vec<[{}; 4]>").is_err());
+        assert!(super::parse_layout("a: Vec<enum { A }>, b: {}, c: [u8; 0]").is_ok());
+    }
+}
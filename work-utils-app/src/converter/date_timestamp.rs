@@ -253,6 +253,11 @@ impl DateConverter {
         });
     }
 
+    /// Show a time, as if it had been entered
+    pub fn set_time(&self, time: DateTime<Utc>) {
+        Self::update_texts(Some(time), &mut self.data.blocking_lock());
+    }
+
     /// Update texts based on a new input (NaiveDateTime)
     /// This update happens asynchronously
     ///
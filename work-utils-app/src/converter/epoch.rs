@@ -0,0 +1,331 @@
+use chrono::{DateTime, Utc};
+use egui::*;
+
+use super::date_timestamp::DateConverter;
+
+/// Shortest epoch during warmup
+const MINIMUM_SLOTS_PER_EPOCH: u64 = 32;
+
+/// Solana's `EpochSchedule`. With warmup, epochs start at 32 slots and double until they reach
+/// `slots_per_epoch`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EpochSchedule {
+    pub slots_per_epoch: u64,
+    pub leader_schedule_slot_offset: u64,
+    pub warmup: bool,
+    pub first_normal_epoch: u64,
+    pub first_normal_slot: u64,
+}
+
+impl Default for EpochSchedule {
+    /// Mainnet: 432,000 slots per epoch, no warmup
+    fn default() -> Self {
+        Self::new(432_000, 432_000, false)
+    }
+}
+
+impl EpochSchedule {
+    pub fn new(slots_per_epoch: u64, leader_schedule_slot_offset: u64, warmup: bool) -> Self {
+        let slots_per_epoch = slots_per_epoch.max(MINIMUM_SLOTS_PER_EPOCH);
+        let (first_normal_epoch, first_normal_slot) = match warmup {
+            true => {
+                let first_normal_epoch = (slots_per_epoch.next_power_of_two().trailing_zeros()
+                    - MINIMUM_SLOTS_PER_EPOCH.trailing_zeros())
+                    as u64;
+                let first_normal_slot =
+                    (2u64.pow(first_normal_epoch as u32) - 1) * MINIMUM_SLOTS_PER_EPOCH;
+                (first_normal_epoch, first_normal_slot)
+            }
+            false => (0, 0),
+        };
+        Self {
+            slots_per_epoch,
+            leader_schedule_slot_offset,
+            warmup,
+            first_normal_epoch,
+            first_normal_slot,
+        }
+    }
+
+    pub fn slots_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch < self.first_normal_epoch {
+            2u64.pow(epoch as u32 + MINIMUM_SLOTS_PER_EPOCH.trailing_zeros())
+        } else {
+            self.slots_per_epoch
+        }
+    }
+
+    /// The epoch of a slot, and the slot's index within it
+    pub fn epoch_and_slot_index(&self, slot: u64) -> (u64, u64) {
+        if slot < self.first_normal_slot {
+            let epoch = ((slot + MINIMUM_SLOTS_PER_EPOCH + 1)
+                .next_power_of_two()
+                .trailing_zeros()
+                - MINIMUM_SLOTS_PER_EPOCH.trailing_zeros()
+                - 1) as u64;
+            let epoch_len = 2u64.pow(epoch as u32 + MINIMUM_SLOTS_PER_EPOCH.trailing_zeros());
+            (epoch, slot - (epoch_len - MINIMUM_SLOTS_PER_EPOCH))
+        } else {
+            let normal_slot_index = slot - self.first_normal_slot;
+            (
+                self.first_normal_epoch + normal_slot_index / self.slots_per_epoch,
+                normal_slot_index % self.slots_per_epoch,
+            )
+        }
+    }
+
+    pub fn first_slot_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch <= self.first_normal_epoch {
+            (2u64.pow(epoch as u32) - 1) * MINIMUM_SLOTS_PER_EPOCH
+        } else {
+            (epoch - self.first_normal_epoch)
+                .saturating_mul(self.slots_per_epoch)
+                .saturating_add(self.first_normal_slot)
+        }
+    }
+
+    pub fn last_slot_in_epoch(&self, epoch: u64) -> u64 {
+        self.first_slot_in_epoch(epoch)
+            .saturating_add(self.slots_in_epoch(epoch) - 1)
+    }
+
+    /// The epoch whose leader schedule is computed at a slot
+    pub fn leader_schedule_epoch(&self, slot: u64) -> u64 {
+        if slot < self.first_normal_slot {
+            self.epoch_and_slot_index(slot).0 + 1
+        } else {
+            let new_slots_since_first_normal_slot = slot - self.first_normal_slot;
+            let new_first_normal_leader_schedule_slot =
+                new_slots_since_first_normal_slot.saturating_add(self.leader_schedule_slot_offset);
+            self.first_normal_epoch + new_first_normal_leader_schedule_slot / self.slots_per_epoch
+        }
+    }
+}
+
+/// Estimated time of a slot from a known slot and time, at a fixed slot duration
+pub fn estimate_slot_time(
+    slot: u64,
+    reference_slot: u64,
+    reference_timestamp: i64,
+    slot_ms: u64,
+) -> Option<DateTime<Utc>> {
+    let offset_ms = (slot as i128 - reference_slot as i128) * slot_ms as i128;
+    let millis = reference_timestamp as i128 * 1000 + offset_ms;
+    DateTime::from_timestamp_millis(i64::try_from(millis).ok()?)
+}
+
+fn parse_u64(input: &str) -> Result<u64, String> {
+    input
+        .trim()
+        .replace([',', '_'], "")
+        .parse::<u64>()
+        .map_err(|e| format!("Invalid number {}: {}", input.trim(), e))
+}
+
+/// Slot and epoch arithmetic for an epoch schedule, with times estimated from a reference slot
+pub struct EpochCalculator {
+    pub schedule: EpochSchedule,
+    pub display_slot: String,
+    pub display_epoch: String,
+
+    pub display_reference_slot: String,
+    pub display_reference_timestamp: String,
+    pub slot_ms: u64,
+
+    pub display_error: Option<String>,
+}
+
+impl Default for EpochCalculator {
+    fn default() -> Self {
+        Self {
+            schedule: EpochSchedule::default(),
+            display_slot: "0".to_string(),
+            display_epoch: "0".to_string(),
+            display_reference_slot: "".to_string(),
+            display_reference_timestamp: "".to_string(),
+            slot_ms: 400,
+            display_error: None,
+        }
+    }
+}
+
+impl EpochCalculator {
+    pub fn ui(&mut self, ui: &mut Ui, date_converter: &DateConverter) {
+        self.display_error = None;
+
+        ui.horizontal(|ui| {
+            let mut slots_per_epoch = self.schedule.slots_per_epoch;
+            let mut leader_schedule_slot_offset = self.schedule.leader_schedule_slot_offset;
+            let mut warmup = self.schedule.warmup;
+
+            ui.label("Slots per epoch: ");
+            let mut changed = ui
+                .add(
+                    DragValue::new(&mut slots_per_epoch)
+                        .clamp_range(MINIMUM_SLOTS_PER_EPOCH..=u32::MAX as u64),
+                )
+                .changed();
+            ui.label("Leader schedule slot offset: ");
+            changed |= ui
+                .add(DragValue::new(&mut leader_schedule_slot_offset))
+                .changed();
+            changed |= ui.checkbox(&mut warmup, "Warmup").changed();
+            if changed {
+                self.schedule =
+                    EpochSchedule::new(slots_per_epoch, leader_schedule_slot_offset, warmup);
+            }
+            if ui.button("Mainnet").clicked() {
+                self.schedule = EpochSchedule::default();
+            }
+        });
+        if self.schedule.warmup {
+            ui.label(format!(
+                "First normal epoch: {}, first normal slot: {}",
+                self.schedule.first_normal_epoch, self.schedule.first_normal_slot
+            ));
+        }
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.label("Reference slot: ");
+            ui.text_edit_singleline(&mut self.display_reference_slot);
+            ui.label("Unix timestamp: ");
+            ui.text_edit_singleline(&mut self.display_reference_timestamp);
+            ui.label("ms per slot: ");
+            ui.add(DragValue::new(&mut self.slot_ms).clamp_range(1..=10_000));
+            if ui
+                .button("Use date converter block")
+                .on_hover_text("The Solana block and timestamp looked up in the date converter")
+                .clicked()
+            {
+                let data = date_converter.data.blocking_lock();
+                self.display_reference_slot = data.display_solana_block.clone();
+                self.display_reference_timestamp = data.display_timestamp.clone();
+            }
+        });
+        let reference = match (
+            self.display_reference_slot.trim(),
+            self.display_reference_timestamp.trim(),
+        ) {
+            ("", "") => None,
+            (slot, timestamp) => match (parse_u64(slot), timestamp.parse::<i64>()) {
+                (Ok(slot), Ok(timestamp)) => Some((slot, timestamp)),
+                (Err(e), _) => {
+                    self.display_error = Some(format!("Reference slot: {}", e));
+                    None
+                }
+                (_, Err(e)) => {
+                    self.display_error = Some(format!("Reference timestamp: {}", e));
+                    None
+                }
+            },
+        };
+        let timezone = date_converter.data.blocking_lock().custom_timezone;
+        let slot_ms = self.slot_ms;
+
+        let time_label = |ui: &mut Ui, slot: u64| {
+            let Some((reference_slot, reference_timestamp)) = reference else {
+                return;
+            };
+            match estimate_slot_time(slot, reference_slot, reference_timestamp, slot_ms) {
+                Some(time) => {
+                    ui.label(format!("~ {}", time));
+                    ui.label(format!("~ {}", time.with_timezone(&timezone)));
+                    if ui.button("Show in date converter").clicked() {
+                        date_converter.set_time(time);
+                    }
+                }
+                None => {
+                    ui.label("Out of range");
+                }
+            }
+        };
+
+        ui.separator();
+        Grid::new("epoch_calculator").show(ui, |ui| {
+            ui.label("Slot: ");
+            ui.text_edit_singleline(&mut self.display_slot);
+            ui.end_row();
+            match parse_u64(&self.display_slot) {
+                Ok(slot) => {
+                    let (epoch, slot_index) = self.schedule.epoch_and_slot_index(slot);
+                    ui.label("Epoch: ");
+                    ui.horizontal(|ui| {
+                        ui.monospace(format!(
+                            "{} (slot {} of {})",
+                            epoch,
+                            slot_index,
+                            self.schedule.slots_in_epoch(epoch)
+                        ));
+                        if ui.button("Show epoch").clicked() {
+                            self.display_epoch = epoch.to_string();
+                        }
+                    });
+                    ui.end_row();
+                    ui.label("Slot time: ");
+                    ui.horizontal(|ui| time_label(ui, slot));
+                    ui.end_row();
+                    ui.label("Leader schedule epoch: ");
+                    ui.monospace(self.schedule.leader_schedule_epoch(slot).to_string());
+                    ui.end_row();
+                }
+                Err(e) => self.display_error = Some(e),
+            }
+
+            ui.label("Epoch: ");
+            ui.text_edit_singleline(&mut self.display_epoch);
+            ui.end_row();
+            match parse_u64(&self.display_epoch) {
+                Ok(epoch) => {
+                    let first = self.schedule.first_slot_in_epoch(epoch);
+                    let last = self.schedule.last_slot_in_epoch(epoch);
+                    ui.label("First slot: ");
+                    ui.horizontal(|ui| {
+                        ui.monospace(first.to_string());
+                        time_label(ui, first);
+                    });
+                    ui.end_row();
+                    ui.label("Last slot: ");
+                    ui.horizontal(|ui| {
+                        ui.monospace(last.to_string());
+                        time_label(ui, last);
+                    });
+                    ui.end_row();
+                }
+                Err(e) => self.display_error = Some(e),
+            }
+        });
+
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EpochSchedule;
+
+    #[test]
+    fn epoch_schedule() {
+        let mainnet = EpochSchedule::default();
+        assert_eq!(mainnet.epoch_and_slot_index(250_000_000), (578, 304_000));
+        assert_eq!(mainnet.first_slot_in_epoch(578), 249_696_000);
+        assert_eq!(mainnet.last_slot_in_epoch(578), 250_127_999);
+        assert_eq!(mainnet.leader_schedule_epoch(250_000_000), 579);
+
+        let warmup = EpochSchedule::new(8192, 8192, true);
+        assert_eq!(warmup.first_normal_epoch, 8);
+        assert_eq!(warmup.first_normal_slot, 8160);
+        for slot in [0, 31, 32, 95, 96, 8159, 8160, 20_000] {
+            let (epoch, index) = warmup.epoch_and_slot_index(slot);
+            assert_eq!(warmup.first_slot_in_epoch(epoch) + index, slot);
+            assert!(slot <= warmup.last_slot_in_epoch(epoch));
+        }
+        assert_eq!(warmup.epoch_and_slot_index(32), (1, 0));
+        assert_eq!(warmup.slots_in_epoch(1), 64);
+
+        let time = super::estimate_slot_time(100, 0, 1_700_000_000, 400).unwrap();
+        assert_eq!(time.timestamp(), 1_700_000_040);
+    }
+}
@@ -0,0 +1,194 @@
+use egui::*;
+use serde::{Deserialize, Serialize};
+
+/// A link out to an explorer. The template's placeholder decides what it links to:
+/// `{address}` for 32 byte accounts, `{signature}` for 64 byte transactions.
+/// `{cluster}` is replaced with the selected cluster, and `{cluster_query}` with `?cluster=<cluster>`,
+/// or nothing on mainnet. Templates with neither are mainnet only.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExplorerLink {
+    pub name: String,
+    pub template: String,
+}
+
+impl ExplorerLink {
+    fn new(name: &str, template: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            template: template.to_string(),
+        }
+    }
+
+    /// The URL for the bytes, if this link applies to their length and the cluster
+    pub fn url(&self, bytes: &[u8], cluster: Cluster) -> Option<String> {
+        let placeholder = match bytes.len() {
+            32 => "{address}",
+            64 => "{signature}",
+            _ => return None,
+        };
+        let has_cluster =
+            self.template.contains("{cluster}") || self.template.contains("{cluster_query}");
+        if !self.template.contains(placeholder) || (!has_cluster && cluster != Cluster::MainnetBeta)
+        {
+            return None;
+        }
+
+        let cluster_query = match cluster {
+            Cluster::MainnetBeta => "".to_string(),
+            cluster => format!("?cluster={}", cluster.name()),
+        };
+        Some(
+            self.template
+                .replace(placeholder, &bs58::encode(bytes).into_string())
+                .replace("{cluster_query}", &cluster_query)
+                .replace("{cluster}", cluster.name()),
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Cluster {
+    MainnetBeta,
+    Devnet,
+    Testnet,
+}
+
+const CLUSTERS: [Cluster; 3] = [Cluster::MainnetBeta, Cluster::Devnet, Cluster::Testnet];
+
+impl Cluster {
+    fn name(self) -> &'static str {
+        match self {
+            Cluster::MainnetBeta => "mainnet-beta",
+            Cluster::Devnet => "devnet",
+            Cluster::Testnet => "testnet",
+        }
+    }
+}
+
+/// Explorer link templates, and the cluster they open on
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExplorerRegistry {
+    pub links: Vec<ExplorerLink>,
+    pub cluster: Cluster,
+}
+
+impl Default for ExplorerRegistry {
+    fn default() -> Self {
+        Self {
+            links: default_links(),
+            cluster: Cluster::MainnetBeta,
+        }
+    }
+}
+
+fn default_links() -> Vec<ExplorerLink> {
+    vec![
+        ExplorerLink::new(
+            "Solscan",
+            "https://solscan.io/account/{address}{cluster_query}",
+        ),
+        ExplorerLink::new(
+            "Solscan",
+            "https://solscan.io/tx/{signature}{cluster_query}",
+        ),
+        ExplorerLink::new(
+            "Solana Explorer",
+            "https://explorer.solana.com/address/{address}{cluster_query}",
+        ),
+        ExplorerLink::new(
+            "Solana Explorer",
+            "https://explorer.solana.com/tx/{signature}{cluster_query}",
+        ),
+        ExplorerLink::new("Solana.fm", "https://solana.fm/address/{address}"),
+        ExplorerLink::new("vybe prod", "https://vybe.fyi/wallets/{address}"),
+        ExplorerLink::new(
+            "vybe staging",
+            "https://alpha.vybeapp.xyz/wallets/{address}",
+        ),
+    ]
+}
+
+impl ExplorerRegistry {
+    /// Buttons for the links that apply to the bytes, and an editor for the registry
+    pub fn ui(&mut self, ui: &mut Ui, bytes: &[u8]) {
+        ComboBox::from_id_source("explorer_cluster")
+            .selected_text(self.cluster.name())
+            .show_ui(ui, |ui| {
+                for cluster in CLUSTERS {
+                    ui.selectable_value(&mut self.cluster, cluster, cluster.name());
+                }
+            });
+
+        for link in &self.links {
+            if let Some(url) = link.url(bytes, self.cluster) {
+                if ui.button(format!("Open in {}", link.name)).clicked() {
+                    ui.ctx().open_url(egui::OpenUrl { url, new_tab: true });
+                }
+            }
+        }
+
+        ui.collapsing("Edit explorer links", |ui| {
+            ui.label("Placeholders: {address}, {signature}, {cluster}, {cluster_query}");
+            let mut removed = None;
+            Grid::new("explorer_links").show(ui, |ui| {
+                for (index, link) in self.links.iter_mut().enumerate() {
+                    ui.text_edit_singleline(&mut link.name);
+                    ui.text_edit_singleline(&mut link.template);
+                    if ui.button("Remove").clicked() {
+                        removed = Some(index);
+                    }
+                    ui.end_row();
+                }
+            });
+            if let Some(index) = removed {
+                self.links.remove(index);
+            }
+            ui.horizontal(|ui| {
+                if ui.button("Add link").clicked() {
+                    self.links.push(ExplorerLink::new("", "https://"));
+                }
+                if ui.button("Reset to defaults").clicked() {
+                    self.links = default_links();
+                }
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cluster;
+
+    #[test]
+    fn links_by_length_and_cluster() {
+        let links = super::default_links();
+        let urls = |bytes: &[u8], cluster| {
+            links
+                .iter()
+                .filter_map(|link| link.url(bytes, cluster))
+                .collect::<Vec<_>>()
+        };
+
+        let address = bs58::encode([0; 32]).into_string();
+        assert_eq!(
+            urls(&[0; 32], Cluster::MainnetBeta),
+            [
+                format!("https://solscan.io/account/{}", address),
+                format!("https://explorer.solana.com/address/{}", address),
+                format!("https://solana.fm/address/{}", address),
+                format!("https://vybe.fyi/wallets/{}", address),
+                format!("https://alpha.vybeapp.xyz/wallets/{}", address),
+            ]
+        );
+        assert_eq!(
+            urls(&[0; 64], Cluster::Devnet)[0],
+            format!(
+                "https://solscan.io/tx/{}?cluster=devnet",
+                bs58::encode([0; 64]).into_string()
+            )
+        );
+        assert_eq!(urls(&[0; 32], Cluster::Testnet).len(), 2);
+        assert!(urls(&[0; 33], Cluster::MainnetBeta).is_empty());
+    }
+}
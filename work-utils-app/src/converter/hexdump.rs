@@ -0,0 +1,133 @@
+use egui::*;
+
+/// xxd-style view of a byte buffer: offsets, hex bytes and an ASCII gutter.
+/// Hovering a byte or selecting a range shows its offset and length.
+#[derive(PartialEq)]
+pub struct HexDump {
+    pub bytes_per_row: usize,
+
+    /// Inclusive range of selected byte indices, in click order
+    pub selection: Option<(usize, usize)>,
+}
+
+impl Default for HexDump {
+    fn default() -> Self {
+        Self {
+            bytes_per_row: 16,
+            selection: None,
+        }
+    }
+}
+
+impl HexDump {
+    pub fn ui(&mut self, ui: &mut Ui, bytes: &[u8]) {
+        // Drop a selection that no longer fits the buffer
+        if let Some((start, end)) = self.selection {
+            if start.max(end) >= bytes.len() {
+                self.selection = None;
+            }
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Bytes per row: ");
+            ui.add(DragValue::new(&mut self.bytes_per_row).clamp_range(1..=64));
+            ui.label(format!("Total: {} bytes", bytes.len()));
+        });
+
+        let mut hovered = None;
+        let mut clicked = None;
+
+        let row_height =
+            ui.text_style_height(&TextStyle::Monospace) + 2.0 * ui.spacing().button_padding.y;
+        let num_rows = bytes.len().div_ceil(self.bytes_per_row);
+        ScrollArea::both()
+            .max_height(300.0)
+            .auto_shrink([false, true])
+            .show_rows(ui, row_height, num_rows, |ui, row_range| {
+                for row in row_range {
+                    let start = row * self.bytes_per_row;
+                    let end = (start + self.bytes_per_row).min(bytes.len());
+
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 0.0;
+                        ui.spacing_mut().button_padding.x = 2.0;
+                        ui.monospace(format!("{:08x}: ", start));
+
+                        // Hex column, padded so the ASCII column lines up on the last row
+                        for index in start..start + self.bytes_per_row {
+                            let Some(byte) = bytes.get(index) else {
+                                ui.monospace("   ");
+                                continue;
+                            };
+                            let text = RichText::new(format!("{:02x}", byte)).monospace();
+                            let response = ui.selectable_label(self.is_selected(index), text);
+                            hovered = hovered.or(response.hovered().then_some(index));
+                            if response.clicked() {
+                                clicked = Some(index);
+                            }
+                            ui.monospace(" ");
+                        }
+
+                        ui.monospace(" ");
+                        for (index, byte) in bytes.iter().enumerate().take(end).skip(start) {
+                            let char = if byte.is_ascii_graphic() || *byte == b' ' {
+                                *byte as char
+                            } else {
+                                '.'
+                            };
+                            let text = RichText::new(char).monospace();
+                            let response = ui.selectable_label(self.is_selected(index), text);
+                            hovered = hovered.or(response.hovered().then_some(index));
+                            if response.clicked() {
+                                clicked = Some(index);
+                            }
+                        }
+                    });
+                }
+            });
+
+        // Click to select a byte, shift-click to extend the selection
+        if let Some(index) = clicked {
+            let shift = ui.input(|i| i.modifiers.shift);
+            self.selection = match self.selection {
+                Some((start, _)) if shift => Some((start, index)),
+                _ => Some((index, index)),
+            };
+        }
+
+        match hovered {
+            Some(index) => ui.monospace(format!("Hovered: offset 0x{:x} ({})", index, index)),
+            None => ui.monospace("Hovered: -"),
+        };
+
+        ui.horizontal(|ui| {
+            if let Some((start, end)) = self.selected_range() {
+                ui.monospace(format!(
+                    "Selected: offset 0x{:x} ({}), length {}",
+                    start,
+                    start,
+                    end - start + 1
+                ));
+                if ui.button("Copy selection as hex").clicked() {
+                    ui.output_mut(|o| o.copied_text = hex::encode(&bytes[start..=end]));
+                }
+                if ui.button("Clear").clicked() {
+                    self.selection = None;
+                }
+            } else {
+                ui.monospace("Selected: - (click a byte, shift-click to extend)");
+            }
+        });
+    }
+
+    /// The selection as an ordered, inclusive range
+    fn selected_range(&self) -> Option<(usize, usize)> {
+        self.selection
+            .map(|(start, end)| (start.min(end), start.max(end)))
+    }
+
+    fn is_selected(&self, index: usize) -> bool {
+        self.selected_range()
+            .is_some_and(|(start, end)| (start..=end).contains(&index))
+    }
+}
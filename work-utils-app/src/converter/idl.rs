@@ -0,0 +1,542 @@
+use egui::*;
+use serde_json::Value;
+
+use super::anchor::discriminator;
+use super::base58_bytes::{BaseBytesConverter, INTEGER_KINDS};
+use super::borsh::{format_layout, BorshDecoder, BorshType, Fields};
+use crate::files::{dropped_file_name, read_dropped_file};
+
+/// Prefix of events emitted through `emit_cpi!`, before the event's own discriminator
+const EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// Defined types can refer to each other. Deeper than this is treated as recursion.
+const MAX_TYPE_DEPTH: usize = 32;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ItemKind {
+    Instruction,
+    Account,
+    Event,
+}
+
+impl ItemKind {
+    fn label(self) -> &'static str {
+        match self {
+            ItemKind::Instruction => "instruction",
+            ItemKind::Account => "account",
+            ItemKind::Event => "event",
+        }
+    }
+}
+
+/// An instruction, account or event, and the layout of its data after the discriminator
+pub struct IdlItem {
+    pub kind: ItemKind,
+    pub name: String,
+    /// 8 bytes unless the IDL sets a custom one (Anchor 0.31+). Empty if the IDL's is invalid.
+    pub discriminator: Vec<u8>,
+    pub layout: Result<Fields, String>,
+}
+
+pub struct Idl {
+    pub name: String,
+    pub items: Vec<IdlItem>,
+}
+
+impl Idl {
+    /// The item whose discriminator the bytes start with, and whether it is an event emitted by CPI.
+    /// If several match, the longest discriminator wins.
+    pub fn identify(&self, bytes: &[u8]) -> Option<(&IdlItem, bool)> {
+        let (bytes, cpi) = match bytes.strip_prefix(&EVENT_IX_TAG[..]) {
+            Some(event) => (event, true),
+            None => (bytes, false),
+        };
+        self.items
+            .iter()
+            .filter(|item| {
+                !item.discriminator.is_empty()
+                    && bytes.starts_with(&item.discriminator)
+                    && (!cpi || item.kind == ItemKind::Event)
+            })
+            .max_by_key(|item| item.discriminator.len())
+            .map(|item| (item, cpi))
+    }
+}
+
+impl IdlItem {
+    /// The whole data as a Borsh layout, starting with the discriminator
+    pub fn definition(&self, cpi: bool) -> Result<String, String> {
+        let bytes = |len| BorshType::Array(Box::new(BorshType::Integer(&INTEGER_KINDS[0])), len);
+        let mut fields = Vec::new();
+        if cpi {
+            fields.push(("event_cpi_tag".to_string(), bytes(EVENT_IX_TAG.len())));
+        }
+        fields.push(("discriminator".to_string(), bytes(self.discriminator.len())));
+        fields.extend(self.layout.clone()?);
+        Ok(format_layout(&fields))
+    }
+}
+
+/// Parse an Anchor IDL, either legacy (before 0.30) or 0.30+.
+/// 0.30+ IDLs list their discriminators, legacy discriminators are derived from names.
+/// An item with an invalid discriminator is kept, with the error as its layout.
+pub fn parse_idl(idl: &Value) -> Result<Idl, String> {
+    let name = idl["metadata"]["name"]
+        .as_str()
+        .or(idl["name"].as_str())
+        .ok_or("Not an Anchor IDL: missing name")?;
+    let types = Types(array(&idl["types"]));
+
+    let mut items = Vec::new();
+    for instruction in array(&idl["instructions"]) {
+        let name = str_field(instruction, "name")?;
+        let args = array(&instruction["args"]);
+        let (discriminator, layout) = match explicit_discriminator(instruction) {
+            Ok(explicit) => (
+                explicit.unwrap_or_else(|| discriminator("global", &snake_case(name)).to_vec()),
+                types.fields(args, 0),
+            ),
+            Err(e) => (Vec::new(), Err(e)),
+        };
+        items.push(IdlItem {
+            kind: ItemKind::Instruction,
+            name: name.to_string(),
+            discriminator,
+            layout,
+        });
+    }
+    for (kind, key) in [(ItemKind::Account, "accounts"), (ItemKind::Event, "events")] {
+        for item in array(&idl[key]) {
+            let name = str_field(item, "name")?;
+            // Legacy IDLs define the type in place, 0.30+ refers to `types`
+            let layout = if item["type"].is_object() {
+                types.definition(&item["type"], 0)
+            } else if item["fields"].is_array() {
+                types
+                    .fields(array(&item["fields"]), 0)
+                    .map(BorshType::Struct)
+            } else {
+                types.defined(name, 0)
+            };
+            let layout = layout.map(|ty| match ty {
+                BorshType::Struct(fields) => fields,
+                ty => vec![("value".to_string(), ty)],
+            });
+            let (discriminator, layout) = match explicit_discriminator(item) {
+                Ok(explicit) => (
+                    explicit.unwrap_or_else(|| discriminator(kind.label(), name).to_vec()),
+                    layout,
+                ),
+                Err(e) => (Vec::new(), Err(e)),
+            };
+            items.push(IdlItem {
+                kind,
+                name: name.to_string(),
+                discriminator,
+                layout,
+            });
+        }
+    }
+
+    Ok(Idl {
+        name: name.to_string(),
+        items,
+    })
+}
+
+fn array(value: &Value) -> &[Value] {
+    value.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Result<&'a str, String> {
+    value[key]
+        .as_str()
+        .ok_or_else(|| format!("Missing {} in {}", key, value))
+}
+
+fn explicit_discriminator(item: &Value) -> Result<Option<Vec<u8>>, String> {
+    if item["discriminator"].is_null() {
+        return Ok(None);
+    }
+    match serde_json::from_value::<Vec<u8>>(item["discriminator"].clone()) {
+        Ok(discriminator) if discriminator.is_empty() => {
+            Err(format!("Empty discriminator for {}", item["name"]))
+        }
+        Ok(discriminator) => Ok(Some(discriminator)),
+        Err(e) => Err(format!("Invalid discriminator for {}: {}", item["name"], e)),
+    }
+}
+
+/// Legacy IDLs name instructions in camelCase, but hash them in snake_case.
+/// Follows `heck`, as Anchor does: a run of capitals is one word (`createATA` is `create_ata`)
+/// and digits stay with the word before them (`swapV2` is `swap_v2`).
+fn snake_case(name: &str) -> String {
+    let mut words = Vec::new();
+    for part in name.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        // Whether the word so far ends in a lowercase or an uppercase letter, ignoring digits
+        let mut lowercase = false;
+        let mut uppercase = false;
+        for (index, &c) in chars.iter().enumerate() {
+            let next = chars.get(index + 1);
+            // A capital starts a new word after lowercase, or before lowercase after capitals
+            let boundary_before =
+                uppercase && c.is_uppercase() && next.is_some_and(|next| next.is_lowercase());
+            if boundary_before {
+                words.push(std::mem::take(&mut word));
+                uppercase = false;
+            }
+            word.extend(c.to_lowercase());
+            if c.is_lowercase() {
+                (lowercase, uppercase) = (true, false);
+            } else if c.is_uppercase() {
+                (lowercase, uppercase) = (false, true);
+            }
+            if lowercase && next.is_some_and(|next| next.is_uppercase()) {
+                words.push(std::mem::take(&mut word));
+                lowercase = false;
+            }
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words.join("_")
+}
+
+/// The IDL's type definitions, to resolve `defined` types against
+struct Types<'a>(&'a [Value]);
+
+impl Types<'_> {
+    fn ty(&self, ty: &Value, depth: usize) -> Result<BorshType, String> {
+        if let Some(name) = ty.as_str() {
+            if let Some(kind) = INTEGER_KINDS.iter().find(|kind| kind.label == name) {
+                return Ok(BorshType::Integer(kind));
+            }
+            return match name {
+                "bool" => Ok(BorshType::Bool),
+                "publicKey" | "pubkey" => Ok(BorshType::Pubkey),
+                "string" => Ok(BorshType::String),
+                "bytes" => Ok(BorshType::Vec(Box::new(BorshType::Integer(
+                    &INTEGER_KINDS[0],
+                )))),
+                _ => Err(format!("Unsupported type: {}", name)),
+            };
+        }
+
+        if let Some(element) = ty.get("vec") {
+            Ok(BorshType::Vec(Box::new(self.ty(element, depth)?)))
+        } else if let Some(inner) = ty.get("option") {
+            Ok(BorshType::Option(Box::new(self.ty(inner, depth)?)))
+        } else if let Some([element, len]) =
+            ty.get("array").and_then(Value::as_array).map(Vec::as_slice)
+        {
+            let len = len
+                .as_u64()
+                .ok_or_else(|| format!("Unsupported array length: {}", len))?;
+            Ok(BorshType::Array(
+                Box::new(self.ty(element, depth)?),
+                len as usize,
+            ))
+        } else if let Some(defined) = ty.get("defined") {
+            // Legacy: "defined": "Name", 0.30+: "defined": { "name": "Name" }
+            let name = defined
+                .as_str()
+                .or(defined["name"].as_str())
+                .ok_or_else(|| format!("Unsupported defined type: {}", defined))?;
+            if !defined["generics"].is_null() {
+                return Err(format!("Generic types are not supported: {}", name));
+            }
+            self.defined(name, depth + 1)
+        } else {
+            Err(format!("Unsupported type: {}", ty))
+        }
+    }
+
+    fn defined(&self, name: &str, depth: usize) -> Result<BorshType, String> {
+        if depth > MAX_TYPE_DEPTH {
+            return Err(format!("Type {} is recursive or nested too deeply", name));
+        }
+        let definition = self
+            .0
+            .iter()
+            .find(|definition| definition["name"].as_str() == Some(name))
+            .ok_or_else(|| format!("Type {} is not defined in the IDL", name))?;
+        if let Some(serialization) = definition["serialization"].as_str() {
+            if serialization != "borsh" {
+                return Err(format!(
+                    "{} uses {} serialization, not Borsh",
+                    name, serialization
+                ));
+            }
+        }
+        self.definition(&definition["type"], depth)
+    }
+
+    /// A `{ "kind": .. }` type definition
+    fn definition(&self, definition: &Value, depth: usize) -> Result<BorshType, String> {
+        match definition["kind"].as_str() {
+            Some("struct") => Ok(BorshType::Struct(
+                self.fields(array(&definition["fields"]), depth)?,
+            )),
+            Some("enum") => {
+                let variants = array(&definition["variants"])
+                    .iter()
+                    .map(|variant| {
+                        let name = str_field(variant, "name")?;
+                        let fields = self.fields(array(&variant["fields"]), depth)?;
+                        Ok((name.to_string(), fields))
+                    })
+                    .collect::<Result<_, String>>()?;
+                Ok(BorshType::Enum(variants))
+            }
+            Some("type") => self.ty(&definition["alias"], depth),
+            _ => Err(format!("Unsupported type definition: {}", definition)),
+        }
+    }
+
+    /// Named `{ "name", "type" }` fields, or bare types for tuple fields
+    fn fields(&self, fields: &[Value], depth: usize) -> Result<Fields, String> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match field["name"].as_str() {
+                Some(name) => Ok((name.to_string(), self.ty(&field["type"], depth)?)),
+                None => Ok((index.to_string(), self.ty(field, depth)?)),
+            })
+            .collect()
+    }
+}
+
+/// Identifies the current bytes as an instruction, account or event of a loaded IDL,
+/// and decodes them with the Borsh decoder
+#[derive(Default)]
+pub struct IdlDecoder {
+    pub display_json: String,
+    pub display_error: Option<String>,
+    pub idl: Option<Idl>,
+
+    /// The layout last sent to the Borsh decoder, so it is only replaced when the bytes change type
+    identified: Option<String>,
+}
+
+impl IdlDecoder {
+    pub fn ui(
+        &mut self,
+        ui: &mut Ui,
+        byte_converter: &BaseBytesConverter,
+        borsh_decoder: &mut BorshDecoder,
+    ) {
+        ui.horizontal(|ui| {
+            ui.add(
+                TextEdit::multiline(&mut self.display_json)
+                    .code_editor()
+                    .desired_rows(2)
+                    .hint_text("Paste an IDL, or drop an IDL .json file onto the window"),
+            );
+            if ui.button("Load").clicked() {
+                match serde_json::from_str(&self.display_json) {
+                    Ok(json) => self.load(&json),
+                    Err(e) => self.display_error = Some(format!("Invalid JSON: {}", e)),
+                }
+            }
+        });
+
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+        let Some(idl) = &self.idl else {
+            return;
+        };
+
+        let count = |kind| idl.items.iter().filter(|item| item.kind == kind).count();
+        ui.label(format!(
+            "{}: {} instructions, {} accounts, {} events",
+            idl.name,
+            count(ItemKind::Instruction),
+            count(ItemKind::Account),
+            count(ItemKind::Event)
+        ));
+
+        let mut load = None;
+        match idl.identify(&byte_converter.bytes) {
+            Some((item, cpi)) => {
+                let name = format!("{}::{}", idl.name, item.name);
+                ui.colored_label(
+                    Color32::LIGHT_GREEN,
+                    format!("Current bytes are {} {}", item.kind.label(), name),
+                );
+                match item.definition(cpi) {
+                    Ok(definition) if self.identified.as_ref() != Some(&definition) => {
+                        load = Some((name, definition));
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        ui.colored_label(Color32::RED, e);
+                    }
+                }
+            }
+            None => {
+                ui.label("No instruction, account or event discriminator starts the bytes");
+            }
+        }
+
+        ui.collapsing("Items", |ui| {
+            Grid::new("idl_items").striped(true).show(ui, |ui| {
+                for item in &idl.items {
+                    ui.label(item.kind.label());
+                    ui.monospace(&item.name);
+                    ui.monospace(hex::encode(&item.discriminator));
+                    match item.definition(false) {
+                        Ok(definition) => {
+                            if ui.button("Decode as").clicked() {
+                                load = Some((format!("{}::{}", idl.name, item.name), definition));
+                            }
+                        }
+                        Err(e) => {
+                            ui.colored_label(Color32::RED, e);
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+
+        if let Some((name, definition)) = load {
+            self.identified = Some(definition.clone());
+            borsh_decoder.load_layout(name, definition);
+        }
+    }
+
+    fn load(&mut self, json: &Value) {
+        self.identified = None;
+        match parse_idl(json) {
+            Ok(idl) => {
+                self.idl = Some(idl);
+                self.display_error = None;
+            }
+            Err(e) => self.display_error = Some(e),
+        }
+    }
+
+    /// Load a dropped .json file if it is an IDL. Returns false for other files, like keypairs.
+    pub fn load_dropped_file(&mut self, file: &DroppedFile) -> bool {
+        if !dropped_file_name(file).ends_with(".json") {
+            return false;
+        }
+        let json = read_dropped_file(file)
+            .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).map_err(|e| e.to_string()));
+        match json {
+            Ok(json) if json.get("instructions").is_some() => {
+                self.load(&json);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::super::borsh::format_layout;
+
+    #[test]
+    fn legacy_and_current_idls() {
+        let legacy = json!({
+            "name": "market",
+            "instructions": [{
+                "name": "initializeMarket",
+                "accounts": [],
+                "args": [{ "name": "fee", "type": { "option": "u16" } }]
+            }],
+            "accounts": [{
+                "name": "Market",
+                "type": { "kind": "struct", "fields": [
+                    { "name": "authority", "type": "publicKey" },
+                    { "name": "side", "type": { "defined": "Side" } }
+                ] }
+            }],
+            "types": [{
+                "name": "Side",
+                "type": { "kind": "enum", "variants": [{ "name": "Bid" }, { "name": "Ask" }] }
+            }]
+        });
+        let idl = super::parse_idl(&legacy).unwrap();
+        let instruction = &idl.items[0];
+        assert_eq!(
+            instruction.discriminator,
+            super::discriminator("global", "initialize_market")
+        );
+        assert_eq!(
+            format_layout(instruction.layout.as_ref().unwrap()),
+            "fee: Option<u16>"
+        );
+        assert_eq!(
+            format_layout(idl.items[1].layout.as_ref().unwrap()),
+            "authority: Pubkey\nside: enum { Bid, Ask }"
+        );
+
+        let current = json!({
+            "address": "11111111111111111111111111111111",
+            "metadata": { "name": "market", "version": "0.1.0", "spec": "0.1.0" },
+            "instructions": [],
+            "events": [{ "name": "Filled", "discriminator": [1, 2, 3, 4, 5, 6, 7, 8] }],
+            "types": [{
+                "name": "Filled",
+                "type": { "kind": "struct", "fields": ["u64", { "vec": "bytes" }] }
+            }]
+        });
+        let idl = super::parse_idl(&current).unwrap();
+        let event = &idl.items[0];
+        assert_eq!(
+            format_layout(event.layout.as_ref().unwrap()),
+            "0: u64\n1: Vec<Vec<u8>>"
+        );
+
+        let mut bytes = super::EVENT_IX_TAG.to_vec();
+        bytes.extend([1, 2, 3, 4, 5, 6, 7, 8]);
+        let (identified, cpi) = idl.identify(&bytes).unwrap();
+        assert_eq!((identified.name.as_str(), cpi), ("Filled", true));
+        assert!(idl.identify(&bytes[8..12]).is_none());
+    }
+
+    #[test]
+    fn custom_discriminators() {
+        let idl = json!({
+            "address": "11111111111111111111111111111111",
+            "metadata": { "name": "custom", "version": "0.1.0", "spec": "0.1.0" },
+            "instructions": [
+                { "name": "short", "discriminator": [7], "accounts": [], "args": [] },
+                { "name": "longer", "discriminator": [7, 8], "accounts": [], "args": [] },
+                { "name": "broken", "discriminator": [300], "accounts": [], "args": [] }
+            ]
+        });
+        let idl = super::parse_idl(&idl).unwrap();
+        assert_eq!(idl.identify(&[7, 1]).unwrap().0.name, "short");
+        assert_eq!(idl.identify(&[7, 8, 1]).unwrap().0.name, "longer");
+        assert_eq!(
+            idl.items[1].definition(false).unwrap(),
+            "discriminator: [u8; 2]"
+        );
+        assert!(idl.items[2].layout.is_err());
+        assert!(idl.identify(&[]).is_none());
+    }
+
+    #[test]
+    fn snake_case_like_anchor() {
+        let cases = [
+            ("initializeMarket", "initialize_market"),
+            ("createATA", "create_ata"),
+            ("HTTPServer", "http_server"),
+            ("swapV2", "swap_v2"),
+            ("v2Swap", "v2_swap"),
+            ("already_snake", "already_snake"),
+        ];
+        for (name, snake) in cases {
+            assert_eq!(super::snake_case(name), snake);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use egui::*;
+
+use super::base58_bytes::{parse_base58, parse_byte_list_u8, BaseBytesConverter};
+use super::solana::public_key_of;
+use crate::files::save_file;
+
+/// Import and export of Solana keypairs, as `solana-keygen` id.json arrays or base58 secret keys.
+/// Holds secret key material: keep it out of anything persisted or shared.
+pub struct KeypairTool {
+    display_input: String,
+    show_secret: bool,
+    file_name: String,
+
+    display_error: Option<String>,
+    keypair: Option<[u8; 64]>,
+}
+
+impl Default for KeypairTool {
+    fn default() -> Self {
+        Self {
+            display_input: "".to_string(),
+            show_secret: false,
+            file_name: "id.json".to_string(),
+            display_error: None,
+            keypair: None,
+        }
+    }
+}
+
+impl KeypairTool {
+    pub fn ui(&mut self, ui: &mut Ui, byte_converter: &mut BaseBytesConverter) {
+        ui.horizontal(|ui| {
+            ui.label("Keypair: ");
+            let response = ui.add(
+                TextEdit::singleline(&mut self.display_input)
+                    .password(!self.show_secret)
+                    .hint_text("id.json array or base58 secret key"),
+            );
+            if response.changed() {
+                self.parse();
+            }
+            ui.checkbox(&mut self.show_secret, "Show secret");
+            if ui.button("Clear").clicked() {
+                *self = Self::default();
+            }
+        });
+
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+        let Some(keypair) = self.keypair else {
+            return;
+        };
+
+        let public_key = bs58::encode(&keypair[32..]).into_string();
+        ui.horizontal(|ui| {
+            ui.label("Public key: ");
+            ui.monospace(&public_key);
+            if ui.button("Copy").clicked() {
+                ui.output_mut(|o| o.copied_text = public_key.clone());
+            }
+            if ui.button("Send to byte converter").clicked() {
+                byte_converter.update_texts(keypair[32..].to_vec());
+            }
+        });
+
+        let id_json = format!("[{}]", keypair.map(|b| b.to_string()).join(","));
+        let base58 = bs58::encode(keypair).into_string();
+        Grid::new("keypair_exports").show(ui, |ui| {
+            for (label, text) in [("id.json: ", &id_json), ("Base58: ", &base58)] {
+                ui.label(label);
+                ui.add(TextEdit::singleline(&mut text.as_str()).password(!self.show_secret));
+                if ui.button("Copy").clicked() {
+                    ui.output_mut(|o| o.copied_text = text.clone());
+                }
+                ui.end_row();
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("File: ");
+            ui.text_edit_singleline(&mut self.file_name);
+            if ui.button("Save id.json").clicked() {
+                if let Err(e) = save_file(&self.file_name, id_json.as_bytes()) {
+                    self.display_error = Some(e);
+                }
+            }
+        });
+    }
+
+    /// The loaded keypair, if it is valid
+    pub fn keypair(&self) -> Option<&[u8; 64]> {
+        self.keypair.as_ref()
+    }
+
+    /// Read the input as an id.json byte array or a base58 secret key,
+    /// and check that its public half belongs to its secret half.
+    fn parse(&mut self) {
+        self.keypair = None;
+        self.display_error = None;
+
+        let input = self.display_input.trim();
+        if input.is_empty() {
+            return;
+        }
+        let bytes = if input.starts_with('[') {
+            parse_byte_list_u8(input)
+        } else {
+            parse_base58(input)
+        };
+        // Errors quote positions only, never the input, since it is a secret
+        let keypair: [u8; 64] = match bytes {
+            Ok(bytes) => match bytes.try_into() {
+                Ok(keypair) => keypair,
+                Err(bytes) => {
+                    let len = bytes.len();
+                    self.display_error = Some(format!("Keypair must be 64 bytes, got {}", len));
+                    return;
+                }
+            },
+            Err(e) => {
+                let position = e
+                    .span
+                    .map(|span| format!(" at position {}", span.start))
+                    .unwrap_or_default();
+                self.display_error = Some(format!("Invalid keypair{}", position));
+                return;
+            }
+        };
+
+        let mut secret_key = [0; 32];
+        secret_key.copy_from_slice(&keypair[..32]);
+        let derived = public_key_of(&secret_key);
+        if derived[..] != keypair[32..] {
+            self.display_error = Some(format!(
+                "Public half {} does not match the secret half, which derives {}",
+                bs58::encode(&keypair[32..]).into_string(),
+                bs58::encode(derived).into_string()
+            ));
+            return;
+        }
+        self.keypair = Some(keypair);
+    }
+}
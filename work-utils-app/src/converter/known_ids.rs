@@ -0,0 +1,77 @@
+//! Well-known program, sysvar and mint addresses
+
+use crate::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, WSOL_ACCOUNT};
+
+pub struct KnownId {
+    pub address: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+const fn known(address: &'static str, name: &'static str, description: &'static str) -> KnownId {
+    KnownId {
+        address,
+        name,
+        description,
+    }
+}
+
+#[rustfmt::skip]
+pub const KNOWN_IDS: [KnownId; 32] = [
+    // Programs
+    known("11111111111111111111111111111111", "System Program", "Creates accounts, assigns owners and transfers lamports"),
+    known(TOKEN_PROGRAM_ID, "SPL Token", "Original token program for mints and token accounts"),
+    known(TOKEN_2022_PROGRAM_ID, "Token-2022", "Token program with extensions (transfer fees, metadata, ...)"),
+    known(ASSOCIATED_TOKEN_PROGRAM_ID, "Associated Token Account", "Creates the canonical token account of a wallet for a mint"),
+    known("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr", "Memo", "Records a UTF-8 memo, optionally checking signers"),
+    known("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo", "Memo (v1)", "Legacy memo program"),
+    known("ComputeBudget111111111111111111111111111111", "Compute Budget", "Sets compute unit limits and priority fees"),
+    known("BPFLoader1111111111111111111111111111111111", "BPF Loader (deprecated)", "Original loader for immutable programs"),
+    known("BPFLoader2111111111111111111111111111111111", "BPF Loader 2", "Loader for immutable programs"),
+    known("BPFLoaderUpgradeab1e11111111111111111111111", "BPF Upgradeable Loader", "Deploys and upgrades programs through program data accounts"),
+    known("LoaderV411111111111111111111111111111111111", "Loader v4", "Successor to the upgradeable loader"),
+    known("Stake11111111111111111111111111111111111111", "Stake Program", "Creates and manages stake accounts and delegations"),
+    known("StakeConfig11111111111111111111111111111111", "Stake Config", "Stake program configuration account"),
+    known("Vote111111111111111111111111111111111111111", "Vote Program", "Validator vote accounts and votes"),
+    known("AddressLookupTab1e1111111111111111111111111", "Address Lookup Table", "Creates and extends lookup tables for v0 transactions"),
+    known("Config1111111111111111111111111111111111111", "Config Program", "Stores configuration data on chain"),
+    known("Ed25519SigVerify111111111111111111111111111", "Ed25519 Program", "Verifies ed25519 signatures in an instruction"),
+    known("KeccakSecp256k11111111111111111111111111111", "Secp256k1 Program", "Verifies secp256k1 signature recovery in an instruction"),
+    // Sysvars
+    known("Sysvar1111111111111111111111111111111111111", "Sysvar Owner", "Owner of every sysvar account"),
+    known("SysvarC1ock11111111111111111111111111111111", "Clock Sysvar", "Slot, epoch and unix timestamp"),
+    known("SysvarEpochSchedu1e111111111111111111111111", "Epoch Schedule Sysvar", "Slots per epoch and warmup"),
+    known("SysvarEpochRewards1111111111111111111111111", "Epoch Rewards Sysvar", "Progress of the current epoch's reward distribution"),
+    known("SysvarFees111111111111111111111111111111111", "Fees Sysvar (deprecated)", "Fee calculator for the current blockhash"),
+    known("Sysvar1nstructions1111111111111111111111111", "Instructions Sysvar", "The instructions of the current transaction"),
+    known("SysvarLastRestartS1ot1111111111111111111111", "Last Restart Slot Sysvar", "Slot of the last cluster restart"),
+    known("SysvarRecentB1ockHashes11111111111111111111", "Recent Blockhashes Sysvar (deprecated)", "Recent blockhashes and their fee calculators"),
+    known("SysvarRent111111111111111111111111111111111", "Rent Sysvar", "Rent rate and exemption threshold"),
+    known("SysvarS1otHashes111111111111111111111111111", "Slot Hashes Sysvar", "Most recent hashes of each slot"),
+    known("SysvarS1otHistory11111111111111111111111111", "Slot History Sysvar", "Which recent slots were produced"),
+    known("SysvarStakeHistory1111111111111111111111111", "Stake History Sysvar", "Stake activation and deactivation per epoch"),
+    // Mints
+    known(WSOL_ACCOUNT, "Wrapped SOL", "Native mint of SPL Token, wraps lamports as a token"),
+    known("9pan9bMn5HatX4EJdBwg9VgCa7Uz5HL8N1m5D3NdXejP", "Wrapped SOL (Token-2022)", "Native mint of Token-2022"),
+];
+
+/// The well-known id the bytes are, if any
+pub fn known_id(bytes: &[u8]) -> Option<&'static KnownId> {
+    if bytes.len() != 32 {
+        return None;
+    }
+    let address = bs58::encode(bytes).into_string();
+    KNOWN_IDS.iter().find(|known| known.address == address)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn known_ids_are_addresses() {
+        for known in &super::KNOWN_IDS {
+            let bytes = bs58::decode(known.address).into_vec().unwrap();
+            assert_eq!(bytes.len(), 32, "{}", known.name);
+            assert_eq!(super::known_id(&bytes).unwrap().name, known.name);
+        }
+    }
+}
@@ -1,19 +1,81 @@
+use address_book::AddressBook;
+use amount::AmountConverter;
+use anchor::DiscriminatorCalculator;
+use ata::AtaDeriver;
 use base58_bytes::BaseBytesConverter;
+use borsh::BorshDecoder;
 use date_timestamp::DateConverter;
+use egui::ScrollArea;
+use epoch::EpochCalculator;
+use explorers::ExplorerRegistry;
+use idl::IdlDecoder;
+use keypair::KeypairTool;
+use pda::PdaDeriver;
+use signature::SignatureTool;
+use transaction::TransactionDecoder;
 
+pub mod address_book;
+pub mod amount;
+pub mod anchor;
+pub mod ata;
 pub mod base58_bytes;
+pub mod borsh;
 pub mod date_timestamp;
+pub mod epoch;
+pub mod explorers;
+pub mod hexdump;
+pub mod idl;
+pub mod keypair;
+pub mod known_ids;
+pub mod pda;
+pub mod signature;
+pub mod solana;
+pub mod token;
+pub mod transaction;
 
-#[derive(Default)]
+/// Only what is worth keeping between sessions is serialized. Keypairs never are.
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
 pub struct ConverterApp {
+    #[serde(skip)]
     base_bytes_converter: BaseBytesConverter,
+    #[serde(skip)]
     date_timestamp_converter: DateConverter,
+    #[serde(skip)]
+    pda_deriver: PdaDeriver,
+    #[serde(skip)]
+    ata_deriver: AtaDeriver,
+    #[serde(skip)]
+    keypair_tool: KeypairTool,
+    #[serde(skip)]
+    signature_tool: SignatureTool,
+    #[serde(skip)]
+    discriminator_calculator: DiscriminatorCalculator,
+    #[serde(skip)]
+    idl_decoder: IdlDecoder,
+    #[serde(skip)]
+    transaction_decoder: TransactionDecoder,
+    #[serde(skip)]
+    amount_converter: AmountConverter,
+    #[serde(skip)]
+    epoch_calculator: EpochCalculator,
+    borsh_decoder: BorshDecoder,
+    explorers: ExplorerRegistry,
+    address_book: AddressBook,
 }
 
 impl eframe::App for ConverterApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Dropped IDLs are loaded as IDLs, any other file as the current bytes
+        for file in ctx.input(|i| i.raw.dropped_files.clone()) {
+            if !self.idl_decoder.load_dropped_file(&file) {
+                self.base_bytes_converter.load_dropped_file(&file);
+            }
+        }
+
         egui::TopBottomPanel::top("Base Bytes Converter").show(ctx, |ui| {
-            self.base_bytes_converter.ui(ui);
+            self.base_bytes_converter
+                .ui(ui, &mut self.explorers, &mut self.address_book);
         });
 
         egui::TopBottomPanel::top("Datetime Converter").show(ctx, |ui| {
@@ -22,8 +84,52 @@ impl eframe::App for ConverterApp {
 
         egui::CentralPanel::default()
             .frame(egui::Frame::dark_canvas(&ctx.style()))
-            .show(ctx, |_| {
-                // TODO: Other panels
+            .show(ctx, |ui| {
+                ScrollArea::vertical().show(ui, |ui| {
+                    ui.collapsing("Amount", |ui| {
+                        self.amount_converter.ui(ui, &mut self.base_bytes_converter);
+                    });
+                    ui.collapsing("Slot and epoch", |ui| {
+                        self.epoch_calculator.ui(ui, &self.date_timestamp_converter);
+                    });
+                    ui.collapsing("Program derived address", |ui| {
+                        self.pda_deriver.ui(ui, &mut self.base_bytes_converter);
+                    });
+                    ui.collapsing("Associated token account", |ui| {
+                        self.ata_deriver.ui(ui, &mut self.base_bytes_converter);
+                    });
+                    ui.collapsing("Keypair", |ui| {
+                        self.keypair_tool.ui(ui, &mut self.base_bytes_converter);
+                    });
+                    ui.collapsing("Sign and verify", |ui| {
+                        self.signature_tool.ui(
+                            ui,
+                            &mut self.base_bytes_converter,
+                            &self.keypair_tool,
+                        );
+                    });
+                    ui.collapsing("Anchor discriminators", |ui| {
+                        self.discriminator_calculator
+                            .ui(ui, &mut self.base_bytes_converter);
+                    });
+                    ui.collapsing("Token mint or account", |ui| {
+                        token::ui(ui, &mut self.base_bytes_converter);
+                    });
+                    ui.collapsing("Transaction", |ui| {
+                        self.transaction_decoder
+                            .ui(ui, &mut self.base_bytes_converter);
+                    });
+                    ui.collapsing("Anchor IDL", |ui| {
+                        self.idl_decoder.ui(
+                            ui,
+                            &self.base_bytes_converter,
+                            &mut self.borsh_decoder,
+                        );
+                    });
+                    ui.collapsing("Borsh layout", |ui| {
+                        self.borsh_decoder.ui(ui, &mut self.base_bytes_converter);
+                    });
+                });
             });
     }
 }
@@ -0,0 +1,207 @@
+use egui::*;
+
+use super::base58_bytes::{
+    parse_base58, parse_byte_list_u8, parse_hex, BaseBytesConverter, ParseError, INTEGER_KINDS,
+};
+use super::solana::find_program_address;
+
+/// Program derived address search over an ordered list of typed seeds
+pub struct PdaDeriver {
+    pub display_program_id: String,
+    pub seeds: Vec<Seed>,
+
+    pub display_error: Option<String>,
+    pub result: Option<([u8; 32], u8)>,
+}
+
+pub struct Seed {
+    pub kind: SeedKind,
+    pub value: String,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SeedKind {
+    Utf8,
+    Pubkey,
+    U8,
+    U16,
+    U32,
+    U64,
+    Hex,
+    ByteList,
+}
+
+const SEED_KINDS: [SeedKind; 8] = [
+    SeedKind::Utf8,
+    SeedKind::Pubkey,
+    SeedKind::U8,
+    SeedKind::U16,
+    SeedKind::U32,
+    SeedKind::U64,
+    SeedKind::Hex,
+    SeedKind::ByteList,
+];
+
+impl SeedKind {
+    fn label(self) -> &'static str {
+        match self {
+            SeedKind::Utf8 => "String (UTF-8)",
+            SeedKind::Pubkey => "Pubkey (base58)",
+            SeedKind::U8 => "u8",
+            SeedKind::U16 => "u16 (LE)",
+            SeedKind::U32 => "u32 (LE)",
+            SeedKind::U64 => "u64 (LE)",
+            SeedKind::Hex => "Hex",
+            SeedKind::ByteList => "Byte list (u8)",
+        }
+    }
+
+    /// The seed bytes, using the same parsers as the byte converter
+    fn parse(self, input: &str) -> Result<Vec<u8>, ParseError> {
+        let [u8_kind, u16_kind, u32_kind, u64_kind, ..] = &INTEGER_KINDS;
+        match self {
+            SeedKind::Utf8 => Ok(input.as_bytes().to_vec()),
+            SeedKind::Pubkey => {
+                let bytes = parse_base58(input)?;
+                match bytes.len() {
+                    32 => Ok(bytes),
+                    len => Err(ParseError::new(format!(
+                        "Pubkey must be 32 bytes, got {}",
+                        len
+                    ))),
+                }
+            }
+            SeedKind::U8 => u8_kind.parse(input, true),
+            SeedKind::U16 => u16_kind.parse(input, true),
+            SeedKind::U32 => u32_kind.parse(input, true),
+            SeedKind::U64 => u64_kind.parse(input, true),
+            SeedKind::Hex => parse_hex(input),
+            SeedKind::ByteList => parse_byte_list_u8(input),
+        }
+    }
+}
+
+impl Default for PdaDeriver {
+    fn default() -> Self {
+        Self {
+            display_program_id: "".to_string(),
+            seeds: vec![Seed {
+                kind: SeedKind::Utf8,
+                value: "".to_string(),
+            }],
+            display_error: None,
+            result: None,
+        }
+    }
+}
+
+impl PdaDeriver {
+    pub fn ui(&mut self, ui: &mut Ui, byte_converter: &mut BaseBytesConverter) {
+        let mut changed = false;
+
+        ui.horizontal(|ui| {
+            ui.label("Program id: ");
+            changed |= ui
+                .text_edit_singleline(&mut self.display_program_id)
+                .changed();
+        });
+
+        // Seeds, in order. Each shows the bytes it contributes.
+        let mut removed = None;
+        Grid::new("pda_seeds").striped(true).show(ui, |ui| {
+            for (index, seed) in self.seeds.iter_mut().enumerate() {
+                ui.label(format!("Seed {}", index + 1));
+                ComboBox::from_id_source(("pda_seed_kind", index))
+                    .selected_text(seed.kind.label())
+                    .show_ui(ui, |ui| {
+                        for kind in SEED_KINDS {
+                            changed |= ui
+                                .selectable_value(&mut seed.kind, kind, kind.label())
+                                .changed();
+                        }
+                    });
+                changed |= ui.text_edit_singleline(&mut seed.value).changed();
+                match seed.kind.parse(&seed.value) {
+                    Ok(bytes) => {
+                        ui.monospace(format!("{} ({} bytes)", hex::encode(&bytes), bytes.len()))
+                    }
+                    Err(e) => ui.colored_label(Color32::RED, e.to_string()),
+                };
+                if ui.button("Remove").clicked() {
+                    removed = Some(index);
+                }
+                ui.end_row();
+            }
+        });
+        if let Some(index) = removed {
+            self.seeds.remove(index);
+            changed = true;
+        }
+        if ui.button("Add seed").clicked() {
+            self.seeds.push(Seed {
+                kind: SeedKind::Utf8,
+                value: "".to_string(),
+            });
+            changed = true;
+        }
+
+        if changed {
+            self.derive();
+        }
+
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+        if let Some((address, bump)) = self.result {
+            let address_base58 = bs58::encode(address).into_string();
+            ui.horizontal(|ui| {
+                ui.label("Address: ");
+                ui.monospace(&address_base58);
+                if ui.button("Copy").clicked() {
+                    ui.output_mut(|o| o.copied_text = address_base58.clone());
+                }
+                if ui.button("Send to byte converter").clicked() {
+                    byte_converter.update_texts(address.to_vec());
+                }
+            });
+            ui.label(format!("Bump: {}", bump));
+        }
+    }
+
+    fn derive(&mut self) {
+        self.result = None;
+        self.display_error = None;
+
+        let program_id = match parse_base58(&self.display_program_id) {
+            Ok(bytes) => match <[u8; 32]>::try_from(bytes) {
+                Ok(program_id) => program_id,
+                Err(bytes) => {
+                    self.display_error =
+                        Some(format!("Program id must be 32 bytes, got {}", bytes.len()));
+                    return;
+                }
+            },
+            Err(e) => {
+                self.display_error = Some(format!("Program id: {}", e));
+                return;
+            }
+        };
+
+        let mut seeds = Vec::new();
+        for (index, seed) in self.seeds.iter().enumerate() {
+            match seed.kind.parse(&seed.value) {
+                Ok(bytes) => seeds.push(bytes),
+                Err(e) => {
+                    self.display_error = Some(format!("Seed {}: {}", index + 1, e));
+                    return;
+                }
+            }
+        }
+
+        let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        match find_program_address(&seeds, &program_id) {
+            Ok(result) => self.result = Some(result),
+            Err(e) => self.display_error = Some(e),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use egui::*;
+
+use super::base58_bytes::{parse_base58, parse_base64, parse_hex, BaseBytesConverter, ParseError};
+use super::keypair::KeypairTool;
+use super::solana::{pubkey_from_base58, sign_message, verify_signature};
+
+/// Ed25519 signing with the loaded keypair, and verification of a pubkey, message and signature
+pub struct SignatureTool {
+    pub message_format: MessageFormat,
+    pub display_message: String,
+    pub display_public_key: String,
+    pub display_signature: String,
+
+    pub display_error: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MessageFormat {
+    Utf8,
+    Hex,
+    Base58,
+    Base64,
+    ByteConverter,
+}
+
+const MESSAGE_FORMATS: [MessageFormat; 5] = [
+    MessageFormat::Utf8,
+    MessageFormat::Hex,
+    MessageFormat::Base58,
+    MessageFormat::Base64,
+    MessageFormat::ByteConverter,
+];
+
+impl MessageFormat {
+    fn label(self) -> &'static str {
+        match self {
+            MessageFormat::Utf8 => "Text (UTF-8)",
+            MessageFormat::Hex => "Hex",
+            MessageFormat::Base58 => "Base58",
+            MessageFormat::Base64 => "Base64",
+            MessageFormat::ByteConverter => "Byte converter",
+        }
+    }
+}
+
+impl Default for SignatureTool {
+    fn default() -> Self {
+        Self {
+            message_format: MessageFormat::Utf8,
+            display_message: "".to_string(),
+            display_public_key: "".to_string(),
+            display_signature: "".to_string(),
+            display_error: None,
+        }
+    }
+}
+
+impl SignatureTool {
+    pub fn ui(
+        &mut self,
+        ui: &mut Ui,
+        byte_converter: &mut BaseBytesConverter,
+        keypair_tool: &KeypairTool,
+    ) {
+        ui.horizontal(|ui| {
+            ui.label("Message: ");
+            ComboBox::from_id_source("signature_message_format")
+                .selected_text(self.message_format.label())
+                .show_ui(ui, |ui| {
+                    for format in MESSAGE_FORMATS {
+                        ui.selectable_value(&mut self.message_format, format, format.label());
+                    }
+                });
+            if self.message_format != MessageFormat::ByteConverter {
+                ui.text_edit_singleline(&mut self.display_message);
+            }
+        });
+
+        let message = self.message(byte_converter);
+        match &message {
+            Ok(message) => ui.label(format!("{} bytes", message.len())),
+            Err(e) => ui.colored_label(Color32::RED, format!("Message: {}", e)),
+        };
+
+        ui.horizontal(|ui| match (keypair_tool.keypair(), &message) {
+            (Some(keypair), Ok(message)) => {
+                let public_key = bs58::encode(&keypair[32..]).into_string();
+                if ui.button(format!("Sign with {}", public_key)).clicked() {
+                    match sign_message(keypair, message) {
+                        Ok(signature) => {
+                            self.display_public_key = public_key;
+                            self.display_signature = bs58::encode(signature).into_string();
+                            self.display_error = None;
+                        }
+                        Err(e) => self.display_error = Some(e),
+                    }
+                }
+            }
+            (None, _) => {
+                ui.label("Load a keypair in the Keypair panel to sign");
+            }
+            _ => {}
+        });
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+
+        Grid::new("signature_verify").show(ui, |ui| {
+            ui.label("Public key: ");
+            ui.text_edit_singleline(&mut self.display_public_key);
+            ui.end_row();
+
+            ui.label("Signature: ");
+            ui.text_edit_singleline(&mut self.display_signature)
+                .on_hover_text("Base58 or hex");
+            if ui.button("Copy").clicked() {
+                ui.output_mut(|o| o.copied_text = self.display_signature.clone());
+            }
+            if ui.button("Send to byte converter").clicked() {
+                if let Ok(signature) = parse_signature(&self.display_signature) {
+                    byte_converter.update_texts(signature.to_vec());
+                }
+            }
+            ui.end_row();
+        });
+
+        if self.display_public_key.is_empty() || self.display_signature.is_empty() {
+            return;
+        }
+        let result = pubkey_from_base58(&self.display_public_key)
+            .map_err(|e| format!("Public key: {}", e))
+            .and_then(|public_key| {
+                let signature = parse_signature(&self.display_signature)
+                    .map_err(|e| format!("Signature: {}", e))?;
+                let message = message.map_err(|e| format!("Message: {}", e))?;
+                verify_signature(&public_key, &message, &signature)
+            });
+        match result {
+            Ok(()) => ui.colored_label(Color32::LIGHT_GREEN, "Valid signature"),
+            Err(e) => ui.colored_label(Color32::RED, format!("Invalid signature: {}", e)),
+        };
+    }
+
+    /// The message bytes, in the selected format
+    fn message(&self, byte_converter: &BaseBytesConverter) -> Result<Vec<u8>, ParseError> {
+        match self.message_format {
+            MessageFormat::Utf8 => Ok(self.display_message.as_bytes().to_vec()),
+            MessageFormat::Hex => parse_hex(&self.display_message),
+            MessageFormat::Base58 => parse_base58(&self.display_message),
+            MessageFormat::Base64 => parse_base64(&self.display_message),
+            MessageFormat::ByteConverter => Ok(byte_converter.bytes.clone()),
+        }
+    }
+}
+
+/// A 64 byte signature, as 128 hex characters or base58
+fn parse_signature(input: &str) -> Result<[u8; 64], String> {
+    let input = input.trim();
+    let bytes = if input.len() == 128 && input.chars().all(|c| c.is_ascii_hexdigit()) {
+        parse_hex(input)
+    } else {
+        parse_base58(input)
+    };
+    let bytes = bytes.map_err(|e| e.to_string())?;
+    <[u8; 64]>::try_from(bytes)
+        .map_err(|bytes| format!("Signature must be 64 bytes, got {}", bytes.len()))
+}
@@ -0,0 +1,209 @@
+//! Offline Solana address helpers
+
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use crate::ASSOCIATED_TOKEN_PROGRAM_ID;
+
+pub const MAX_SEEDS: usize = 16;
+pub const MAX_SEED_LEN: usize = 32;
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+/// Whether the bytes are a valid ed25519 point.
+/// Wallets (keypair public keys) are on the curve, program derived addresses are not.
+pub fn is_on_curve(bytes: &[u8; 32]) -> bool {
+    CompressedEdwardsY(*bytes).decompress().is_some()
+}
+
+/// Decode a base58 address, which must be exactly 32 bytes
+pub fn pubkey_from_base58(input: &str) -> Result<[u8; 32], String> {
+    let bytes = bs58::decode(input.trim())
+        .into_vec()
+        .map_err(|e| e.to_string())?;
+    <[u8; 32]>::try_from(bytes)
+        .map_err(|bytes| format!("Address must be 32 bytes, got {}", bytes.len()))
+}
+
+/// The public key of an ed25519 secret key (the first half of a Solana keypair)
+pub fn public_key_of(secret_key: &[u8; 32]) -> [u8; 32] {
+    SigningKey::from_bytes(secret_key)
+        .verifying_key()
+        .to_bytes()
+}
+
+/// Sign a message with a 64 byte keypair (secret half, then public half)
+pub fn sign_message(keypair: &[u8; 64], message: &[u8]) -> Result<[u8; 64], String> {
+    let signing_key = SigningKey::from_keypair_bytes(keypair).map_err(|e| e.to_string())?;
+    Ok(signing_key.sign(message).to_bytes())
+}
+
+/// Verify a signature the way the ed25519 program does, rejecting weak keys and malleable signatures
+pub fn verify_signature(
+    public_key: &[u8; 32],
+    message: &[u8],
+    signature: &[u8; 64],
+) -> Result<(), String> {
+    let verifying_key = VerifyingKey::from_bytes(public_key).map_err(|e| e.to_string())?;
+    verifying_key
+        .verify_strict(message, &Signature::from_bytes(signature))
+        .map_err(|e| e.to_string())
+}
+
+/// Derive a program address from seeds that already include the bump.
+/// None if the result is on the curve, in which case it is not a valid PDA.
+pub fn create_program_address(seeds: &[&[u8]], program_id: &[u8; 32]) -> Option<[u8; 32]> {
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        hasher.update(seed);
+    }
+    hasher.update(program_id);
+    hasher.update(PDA_MARKER);
+    let address: [u8; 32] = hasher.finalize().into();
+
+    (!is_on_curve(&address)).then_some(address)
+}
+
+/// Search for the highest bump that gives a valid program address, like `Pubkey::find_program_address`.
+/// Returns the address and the bump.
+pub fn find_program_address(
+    seeds: &[&[u8]],
+    program_id: &[u8; 32],
+) -> Result<([u8; 32], u8), String> {
+    // The bump takes up the last seed slot
+    if seeds.len() >= MAX_SEEDS {
+        return Err(format!(
+            "Too many seeds: {} (max {})",
+            seeds.len(),
+            MAX_SEEDS - 1
+        ));
+    }
+    if let Some((index, seed)) = seeds
+        .iter()
+        .enumerate()
+        .find(|(_, seed)| seed.len() > MAX_SEED_LEN)
+    {
+        return Err(format!(
+            "Seed {} is too long: {} bytes (max {})",
+            index + 1,
+            seed.len(),
+            MAX_SEED_LEN
+        ));
+    }
+
+    // Like `try_find_program_address`, bump 0 is never tried
+    for bump in (1..=u8::MAX).rev() {
+        let bump_seed = [bump];
+        let mut seeds = seeds.to_vec();
+        seeds.push(&bump_seed);
+        if let Some(address) = create_program_address(&seeds, program_id) {
+            return Ok((address, bump));
+        }
+    }
+    Err("Unable to find a valid program address".to_string())
+}
+
+/// The associated token account of a wallet for a mint, owned by the given token program
+pub fn find_associated_token_address(
+    wallet: &[u8; 32],
+    mint: &[u8; 32],
+    token_program_id: &[u8; 32],
+) -> Result<[u8; 32], String> {
+    let program_id = pubkey_from_base58(ASSOCIATED_TOKEN_PROGRAM_ID)?;
+    find_program_address(&[wallet, token_program_id, mint], &program_id).map(|(address, _)| address)
+}
+
+#[cfg(test)]
+mod tests {
+    fn decode(base58: &str) -> [u8; 32] {
+        bs58::decode(base58).into_vec().unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn on_curve() {
+        assert!(super::is_on_curve(&decode(crate::WYATT_TEST_ACCOUNT)));
+        assert!(super::is_on_curve(&decode(crate::VYBE_STAKE_VALIDATOR)));
+        assert!(!super::is_on_curve(&[2; 32]));
+    }
+
+    #[test]
+    fn public_key() {
+        // RFC 8032, test 1
+        let secret_key = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let public_key = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+        let secret_key = hex::decode(secret_key).unwrap().try_into().unwrap();
+        assert_eq!(hex::encode(super::public_key_of(&secret_key)), public_key);
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        // RFC 8032, test 1: the empty message
+        let keypair = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60\
+                       d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+        let signature = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+                         5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+        let keypair: [u8; 64] = hex::decode(keypair).unwrap().try_into().unwrap();
+        let public_key = keypair[32..].try_into().unwrap();
+
+        let signed = super::sign_message(&keypair, b"").unwrap();
+        assert_eq!(hex::encode(signed), signature);
+        assert!(super::verify_signature(&public_key, b"", &signed).is_ok());
+        assert!(super::verify_signature(&public_key, b"tampered", &signed).is_err());
+    }
+
+    #[test]
+    fn program_address() {
+        let program_id = decode("BPFLoaderUpgradeab1e11111111111111111111111");
+        let public_key = decode("SeedPubey1111111111111111111111111111111111");
+
+        let cases: [(&[&[u8]], &str); 4] = [
+            (&[b"", &[1]], "BwqrghZA2htAcqq8dzP1WDAhTXYTYWj7CHxF5j7TDBAe"),
+            (
+                &["☉".as_bytes(), &[0]],
+                "13yWmRpaTR4r5nAktwLqMpRNr28tnVUZw26rTvPSSB19",
+            ),
+            (
+                &[b"Talking", b"Squirrels"],
+                "2fnQrngrQT4SeLcdToJAD96phoEjNL2man2kfRLCASVk",
+            ),
+            (
+                &[&public_key, &[1]],
+                "976ymqVnfE32QFe6NfGDctSvVa36LWnvYxhU6G2232YL",
+            ),
+        ];
+        for (seeds, expected) in cases {
+            let address = super::create_program_address(seeds, &program_id).unwrap();
+            assert_eq!(bs58::encode(address).into_string(), expected);
+        }
+
+        let (address, bump) =
+            super::find_program_address(&[b"Lil'", b"Bits"], &program_id).unwrap();
+        let with_bump = super::create_program_address(&[b"Lil'", b"Bits", &[bump]], &program_id);
+        assert_eq!(with_bump, Some(address));
+        assert!(super::find_program_address(&[&[0; 33]], &program_id).is_err());
+    }
+
+    #[test]
+    fn associated_token_address() {
+        // spl-token JS tests, `getAssociatedTokenAddress`
+        let wallet = decode("B8UwBUUnKwCyKuGMbFKWaG7exYdDk2ozZrPg72NyVbfj");
+        let mint = decode("7o36UsWR1JQLpZ9PE2gn9L4SQ69CNNiWAXd4Jt7rqz9Z");
+
+        let cases = [
+            (
+                crate::TOKEN_PROGRAM_ID,
+                "DShWnroshVbeUp28oopA3Pu7oFPDBtC1DBmPECXXAQ9n",
+            ),
+            (
+                crate::TOKEN_2022_PROGRAM_ID,
+                "6WD1d4QUPGyZ9pnwNqN1W6fsBd9zoJwZkDg9s7bYxVmq",
+            ),
+        ];
+        for (token_program_id, expected) in cases {
+            let address =
+                super::find_associated_token_address(&wallet, &mint, &decode(token_program_id))
+                    .unwrap();
+            assert_eq!(bs58::encode(address).into_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use egui::*;
+
+use super::base58_bytes::BaseBytesConverter;
+use super::borsh::{decode_layout, parse_layout, BorshType, DecodedField, DecodedValue};
+use super::solana::pubkey_from_base58;
+
+pub const MINT_LEN: usize = 82;
+pub const ACCOUNT_LEN: usize = 165;
+/// Longer than an account, but never Token-2022: extended accounts are padded past this length
+pub const MULTISIG_LEN: usize = 355;
+/// Signer slots of a multisig, of which the first `n` are used
+const MAX_SIGNERS: usize = 11;
+
+/// Token-2022 extension types, their names and Borsh layouts where they have a fixed one.
+/// Optional authorities are all zeroes when unset.
+const EXTENSIONS: [(u16, &str, Option<&str>); 27] = [
+    (1, "TransferFeeConfig", Some("transfer_fee_config_authority: Pubkey\nwithdraw_withheld_authority: Pubkey\nwithheld_amount: u64\nolder_transfer_fee: { epoch: u64, maximum_fee: u64, transfer_fee_basis_points: u16 }\nnewer_transfer_fee: { epoch: u64, maximum_fee: u64, transfer_fee_basis_points: u16 }")),
+    (2, "TransferFeeAmount", Some("withheld_amount: u64")),
+    (3, "MintCloseAuthority", Some("close_authority: Pubkey")),
+    (4, "ConfidentialTransferMint", None),
+    (5, "ConfidentialTransferAccount", None),
+    (6, "DefaultAccountState", Some("state: enum { Uninitialized, Initialized, Frozen }")),
+    (7, "ImmutableOwner", Some("")),
+    (8, "MemoTransfer", Some("require_incoming_transfer_memos: bool")),
+    (9, "NonTransferable", Some("")),
+    (10, "InterestBearingConfig", Some("rate_authority: Pubkey\ninitialization_timestamp: i64\npre_update_average_rate: i16\nlast_update_timestamp: i64\ncurrent_rate: i16")),
+    (11, "CpiGuard", Some("lock_cpi: bool")),
+    (12, "PermanentDelegate", Some("delegate: Pubkey")),
+    (13, "NonTransferableAccount", Some("")),
+    (14, "TransferHook", Some("authority: Pubkey\nprogram_id: Pubkey")),
+    (15, "TransferHookAccount", Some("transferring: bool")),
+    (16, "ConfidentialTransferFeeConfig", None),
+    (17, "ConfidentialTransferFeeAmount", None),
+    (18, "MetadataPointer", Some("authority: Pubkey\nmetadata_address: Pubkey")),
+    (19, "TokenMetadata", Some("update_authority: Pubkey\nmint: Pubkey\nname: String\nsymbol: String\nuri: String\nadditional_metadata: Vec<{ key: String, value: String }>")),
+    (20, "GroupPointer", Some("authority: Pubkey\ngroup_address: Pubkey")),
+    (21, "TokenGroup", Some("update_authority: Pubkey\nmint: Pubkey\nsize: u64\nmax_size: u64")),
+    (22, "GroupMemberPointer", Some("authority: Pubkey\nmember_address: Pubkey")),
+    (23, "TokenGroupMember", Some("mint: Pubkey\ngroup: Pubkey\nmember_number: u64")),
+    (24, "ConfidentialMintBurn", None),
+    (25, "ScaledUiAmount", None),
+    (26, "Pausable", Some("authority: Pubkey\npaused: bool")),
+    (27, "PausableAccount", Some("")),
+];
+
+pub enum FieldValue {
+    Text(String),
+    Pubkey([u8; 32]),
+}
+
+pub struct Extension {
+    pub name: String,
+    pub fields: Result<Vec<(String, FieldValue)>, String>,
+}
+
+/// A mint, token account or multisig, and for Token-2022 its extensions
+pub struct TokenAccount {
+    pub kind: &'static str,
+    pub fields: Vec<(String, FieldValue)>,
+    pub extensions: Vec<Extension>,
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+fn pubkey_at(bytes: &[u8], offset: usize) -> [u8; 32] {
+    bytes[offset..offset + 32].try_into().unwrap()
+}
+
+/// A `COption`, which unlike Borsh's Option has a 4 byte tag and is always full size
+fn coption_at(bytes: &[u8], offset: usize, name: &str) -> Result<Option<usize>, String> {
+    match u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) {
+        0 => Ok(None),
+        1 => Ok(Some(offset + 4)),
+        tag => Err(format!("Invalid option tag for {}: {}", name, tag)),
+    }
+}
+
+fn coption_pubkey(bytes: &[u8], offset: usize, name: &str) -> Result<FieldValue, String> {
+    Ok(match coption_at(bytes, offset, name)? {
+        Some(offset) => FieldValue::Pubkey(pubkey_at(bytes, offset)),
+        None => FieldValue::Text("None".to_string()),
+    })
+}
+
+fn text(value: impl ToString) -> FieldValue {
+    FieldValue::Text(value.to_string())
+}
+
+/// Decode an SPL Token or Token-2022 mint, token account or multisig from its length and account type
+pub fn decode_token_account(bytes: &[u8]) -> Result<TokenAccount, String> {
+    if bytes.len() == MULTISIG_LEN {
+        return Ok(TokenAccount {
+            kind: "Multisig",
+            fields: decode_multisig(bytes)?,
+            extensions: Vec::new(),
+        });
+    }
+
+    let account_type = match bytes.len() {
+        MINT_LEN => 1,
+        ACCOUNT_LEN => 2,
+        len if len > ACCOUNT_LEN => bytes[ACCOUNT_LEN],
+        len => {
+            return Err(format!(
+                "Not a mint ({} bytes) or token account ({} bytes): {} bytes",
+                MINT_LEN, ACCOUNT_LEN, len
+            ))
+        }
+    };
+
+    let (kind, fields) = match account_type {
+        1 => ("Mint", decode_mint(bytes)?),
+        2 => ("Token account", decode_account(bytes)?),
+        account_type => return Err(format!("Unknown account type: {}", account_type)),
+    };
+    let extensions = match bytes.get(ACCOUNT_LEN + 1..) {
+        Some(tlv) => decode_extensions(tlv)?,
+        None => Vec::new(),
+    };
+
+    Ok(TokenAccount {
+        kind,
+        fields,
+        extensions,
+    })
+}
+
+fn decode_mint(bytes: &[u8]) -> Result<Vec<(String, FieldValue)>, String> {
+    Ok(vec![
+        (
+            "mint_authority".to_string(),
+            coption_pubkey(bytes, 0, "mint_authority")?,
+        ),
+        ("supply".to_string(), text(u64_at(bytes, 36))),
+        ("decimals".to_string(), text(bytes[44])),
+        ("is_initialized".to_string(), text(bytes[45] != 0)),
+        (
+            "freeze_authority".to_string(),
+            coption_pubkey(bytes, 46, "freeze_authority")?,
+        ),
+    ])
+}
+
+fn decode_account(bytes: &[u8]) -> Result<Vec<(String, FieldValue)>, String> {
+    let state = match bytes[108] {
+        0 => "Uninitialized",
+        1 => "Initialized",
+        2 => "Frozen",
+        state => return Err(format!("Invalid account state: {}", state)),
+    };
+    let is_native = match coption_at(bytes, 109, "is_native")? {
+        Some(offset) => format!("Some({} rent exempt lamports)", u64_at(bytes, offset)),
+        None => "None".to_string(),
+    };
+
+    Ok(vec![
+        ("mint".to_string(), FieldValue::Pubkey(pubkey_at(bytes, 0))),
+        (
+            "owner".to_string(),
+            FieldValue::Pubkey(pubkey_at(bytes, 32)),
+        ),
+        ("amount".to_string(), text(u64_at(bytes, 64))),
+        (
+            "delegate".to_string(),
+            coption_pubkey(bytes, 72, "delegate")?,
+        ),
+        ("state".to_string(), text(state)),
+        ("is_native".to_string(), text(is_native)),
+        ("delegated_amount".to_string(), text(u64_at(bytes, 121))),
+        (
+            "close_authority".to_string(),
+            coption_pubkey(bytes, 129, "close_authority")?,
+        ),
+    ])
+}
+
+fn decode_multisig(bytes: &[u8]) -> Result<Vec<(String, FieldValue)>, String> {
+    let (m, n) = (bytes[0], bytes[1] as usize);
+    if n > MAX_SIGNERS {
+        return Err(format!(
+            "Invalid multisig: {} signers, at most {}",
+            n, MAX_SIGNERS
+        ));
+    }
+
+    let mut fields = vec![
+        ("m".to_string(), text(m)),
+        ("n".to_string(), text(n)),
+        ("is_initialized".to_string(), text(bytes[2] != 0)),
+    ];
+    for index in 0..n {
+        fields.push((
+            format!("signers[{}]", index),
+            FieldValue::Pubkey(pubkey_at(bytes, 3 + 32 * index)),
+        ));
+    }
+    Ok(fields)
+}
+
+/// Type-length-value entries: u16 type, u16 length, then the value. Type 0 is padding.
+fn decode_extensions(mut tlv: &[u8]) -> Result<Vec<Extension>, String> {
+    let mut extensions = Vec::new();
+    while tlv.len() >= 4 {
+        let extension_type = u16::from_le_bytes([tlv[0], tlv[1]]);
+        let len = u16::from_le_bytes([tlv[2], tlv[3]]) as usize;
+        if extension_type == 0 {
+            break;
+        }
+        let value = tlv.get(4..4 + len).ok_or_else(|| {
+            format!(
+                "Extension {} needs {} bytes, have {}",
+                extension_type,
+                len,
+                tlv.len() - 4
+            )
+        })?;
+        tlv = &tlv[4 + len..];
+
+        let extension = EXTENSIONS.iter().find(|(t, _, _)| *t == extension_type);
+        extensions.push(match extension {
+            Some((_, name, Some(layout))) => Extension {
+                name: name.to_string(),
+                fields: decode_extension(layout, value),
+            },
+            Some((_, name, None)) => Extension {
+                name: name.to_string(),
+                fields: Ok(vec![("data".to_string(), text(hex::encode(value)))]),
+            },
+            None => Extension {
+                name: format!("Unknown extension {}", extension_type),
+                fields: Ok(vec![("data".to_string(), text(hex::encode(value)))]),
+            },
+        });
+    }
+    Ok(extensions)
+}
+
+fn decode_extension(layout: &str, value: &[u8]) -> Result<Vec<(String, FieldValue)>, String> {
+    let (decoded, _) = decode_layout(&parse_layout(layout)?, value)?;
+    let mut fields = Vec::new();
+    for field in &decoded {
+        flatten(&field.name, field, &mut fields);
+    }
+    Ok(fields)
+}
+
+/// Nested fields as rows named by their path, like `older_transfer_fee.epoch`
+fn flatten(path: &str, field: &DecodedField, fields: &mut Vec<(String, FieldValue)>) {
+    match &field.value {
+        DecodedValue::Leaf { text, .. } => {
+            let value = match (&field.ty, pubkey_from_base58(text)) {
+                (BorshType::Pubkey, Ok(pubkey)) => FieldValue::Pubkey(pubkey),
+                _ => FieldValue::Text(text.clone()),
+            };
+            fields.push((path.to_string(), value));
+        }
+        DecodedValue::None => fields.push((path.to_string(), text("None"))),
+        DecodedValue::Children(children) => {
+            for child in children {
+                let separator = if child.name.starts_with('[') { "" } else { "." };
+                flatten(
+                    &format!("{}{}{}", path, separator, child.name),
+                    child,
+                    fields,
+                );
+            }
+        }
+    }
+}
+
+/// Decodes the byte converter's buffer as a mint, token account or multisig.
+/// Clicking a pubkey loads it into the byte converter.
+pub fn ui(ui: &mut Ui, byte_converter: &mut BaseBytesConverter) {
+    let account = match decode_token_account(&byte_converter.bytes) {
+        Ok(account) => account,
+        Err(e) => {
+            ui.label(e);
+            return;
+        }
+    };
+
+    let mut clicked = None;
+    let mut fields_ui = |ui: &mut Ui, id: &str, fields: &[(String, FieldValue)]| {
+        Grid::new(id).striped(true).show(ui, |ui| {
+            for (name, value) in fields {
+                ui.label(name);
+                match value {
+                    FieldValue::Text(text) => {
+                        ui.monospace(text);
+                    }
+                    FieldValue::Pubkey(pubkey) => {
+                        let text = RichText::new(bs58::encode(pubkey).into_string()).monospace();
+                        if ui
+                            .link(text)
+                            .on_hover_text("Load into the byte converter")
+                            .clicked()
+                        {
+                            clicked = Some(*pubkey);
+                        }
+                    }
+                }
+                ui.end_row();
+            }
+        });
+    };
+
+    ui.label(account.kind);
+    fields_ui(ui, "token_fields", &account.fields);
+    for (index, extension) in account.extensions.iter().enumerate() {
+        ui.label(format!("Extension: {}", extension.name));
+        match &extension.fields {
+            Ok(fields) => fields_ui(ui, &format!("token_extension_{}", index), fields),
+            Err(e) => {
+                ui.colored_label(Color32::RED, e);
+            }
+        }
+    }
+
+    if let Some(pubkey) = clicked {
+        byte_converter.update_texts(pubkey.to_vec());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FieldValue;
+
+    fn field<'a>(fields: &'a [(String, FieldValue)], name: &str) -> &'a FieldValue {
+        &fields.iter().find(|(n, _)| n == name).unwrap().1
+    }
+
+    #[test]
+    fn decode_mint_and_extensions() {
+        let mut mint = vec![0; super::MINT_LEN];
+        mint[..4].copy_from_slice(&1u32.to_le_bytes());
+        mint[4..36].copy_from_slice(&[7; 32]);
+        mint[36..44].copy_from_slice(&1_000_000u64.to_le_bytes());
+        mint[44] = 6;
+        mint[45] = 1;
+
+        let decoded = super::decode_token_account(&mint).unwrap();
+        assert_eq!(decoded.kind, "Mint");
+        assert!(matches!(
+            field(&decoded.fields, "mint_authority"),
+            FieldValue::Pubkey([7, ..])
+        ));
+        assert!(
+            matches!(field(&decoded.fields, "freeze_authority"), FieldValue::Text(t) if t == "None")
+        );
+
+        // Token-2022: padded to the account length, then the account type and extensions
+        let mut extended = mint.clone();
+        extended.resize(super::ACCOUNT_LEN, 0);
+        extended.push(1);
+        extended.extend([18, 0, 64, 0]);
+        extended.extend([1; 32]);
+        extended.extend([2; 32]);
+        extended.extend([9, 0, 0, 0]);
+
+        let decoded = super::decode_token_account(&extended).unwrap();
+        assert_eq!(decoded.kind, "Mint");
+        assert_eq!(decoded.extensions.len(), 2);
+        let pointer = decoded.extensions[0].fields.as_ref().unwrap();
+        assert!(matches!(
+            field(pointer, "metadata_address"),
+            FieldValue::Pubkey([2, ..])
+        ));
+        assert_eq!(decoded.extensions[1].name, "NonTransferable");
+
+        assert!(super::decode_token_account(&mint[..81]).is_err());
+    }
+
+    #[test]
+    fn decode_multisig() {
+        // 2 of 3, with byte 165 (inside the sixth signer slot) looking like a mint's account type
+        let mut multisig = vec![0; super::MULTISIG_LEN];
+        multisig[..3].copy_from_slice(&[2, 3, 1]);
+        for index in 0..3 {
+            multisig[3 + 32 * index..35 + 32 * index].fill(index as u8 + 1);
+        }
+        multisig[super::ACCOUNT_LEN] = 1;
+
+        let decoded = super::decode_token_account(&multisig).unwrap();
+        assert_eq!(decoded.kind, "Multisig");
+        assert!(decoded.extensions.is_empty());
+        assert_eq!(decoded.fields.len(), 6);
+        assert!(matches!(
+            field(&decoded.fields, "signers[2]"),
+            FieldValue::Pubkey([3, ..])
+        ));
+    }
+}
@@ -0,0 +1,473 @@
+use egui::*;
+
+use super::base58_bytes::{parse_base58, parse_base64, BaseBytesConverter};
+use super::known_ids::known_id;
+
+/// Set on the first message byte of versioned transactions, with the version in the low bits
+const VERSION_PREFIX: u8 = 0x80;
+
+pub struct MessageHeader {
+    pub num_required_signatures: u8,
+    pub num_readonly_signed_accounts: u8,
+    pub num_readonly_unsigned_accounts: u8,
+}
+
+pub struct CompiledInstruction {
+    pub program_id_index: u8,
+    pub accounts: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+pub struct AddressTableLookup {
+    pub account_key: [u8; 32],
+    pub writable_indexes: Vec<u8>,
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// A transaction as it is sent over the wire: legacy, or versioned with address table lookups
+pub struct Transaction {
+    pub signatures: Vec<[u8; 64]>,
+    /// None for legacy transactions
+    pub version: Option<u8>,
+    pub header: MessageHeader,
+    pub account_keys: Vec<[u8; 32]>,
+    pub recent_blockhash: [u8; 32],
+    pub instructions: Vec<CompiledInstruction>,
+    pub address_table_lookups: Vec<AddressTableLookup>,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize, what: &str) -> Result<&'a [u8], String> {
+        let end = self.offset + len;
+        let bytes = self.bytes.get(self.offset..end).ok_or_else(|| {
+            format!(
+                "Unexpected end of data reading {} at byte {}: needs {} bytes, have {}",
+                what,
+                self.offset,
+                len,
+                self.bytes.len() - self.offset
+            )
+        })?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self, what: &str) -> Result<[u8; N], String> {
+        Ok(self.take(N, what)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self, what: &str) -> Result<u8, String> {
+        Ok(self.take(1, what)?[0])
+    }
+
+    /// Short vec length: 7 bits per byte, least significant first, high bit set if more follow
+    fn compact_u16(&mut self, what: &str) -> Result<usize, String> {
+        let offset = self.offset;
+        let mut value = 0;
+        for index in 0..3 {
+            let byte = self.u8(what)?;
+            value |= ((byte & 0x7f) as usize) << (index * 7);
+            if byte & 0x80 == 0 {
+                return match value > u16::MAX as usize {
+                    true => Err(format!(
+                        "Length of {} at byte {} overflows u16",
+                        what, offset
+                    )),
+                    false => Ok(value),
+                };
+            }
+        }
+        Err(format!(
+            "Length of {} at byte {} is longer than 3 bytes",
+            what, offset
+        ))
+    }
+
+    fn short_vec<T>(
+        &mut self,
+        what: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let len = self.compact_u16(what)?;
+        (0..len).map(|_| item(self)).collect()
+    }
+}
+
+pub fn decode_transaction(bytes: &[u8]) -> Result<Transaction, String> {
+    let mut reader = Reader { bytes, offset: 0 };
+    let signatures = reader.short_vec("signatures", |r| r.array("signature"))?;
+
+    let version = match reader.bytes.get(reader.offset) {
+        Some(prefix) if prefix & VERSION_PREFIX != 0 => {
+            let version = reader.u8("version")? & !VERSION_PREFIX;
+            if version != 0 {
+                return Err(format!("Unsupported transaction version: {}", version));
+            }
+            Some(version)
+        }
+        _ => None,
+    };
+    let header = MessageHeader {
+        num_required_signatures: reader.u8("header")?,
+        num_readonly_signed_accounts: reader.u8("header")?,
+        num_readonly_unsigned_accounts: reader.u8("header")?,
+    };
+    let account_keys = reader.short_vec("account keys", |r| r.array("account key"))?;
+    let recent_blockhash = reader.array("recent blockhash")?;
+    let instructions = reader.short_vec("instructions", |r| {
+        Ok(CompiledInstruction {
+            program_id_index: r.u8("program id index")?,
+            accounts: r.short_vec("instruction accounts", |r| r.u8("account index"))?,
+            data: r.short_vec("instruction data", |r| r.u8("data"))?,
+        })
+    })?;
+    let address_table_lookups = match version {
+        Some(_) => reader.short_vec("address table lookups", |r| {
+            Ok(AddressTableLookup {
+                account_key: r.array("lookup table")?,
+                writable_indexes: r.short_vec("writable indexes", |r| r.u8("index"))?,
+                readonly_indexes: r.short_vec("readonly indexes", |r| r.u8("index"))?,
+            })
+        })?,
+        None => Vec::new(),
+    };
+
+    if reader.offset != bytes.len() {
+        return Err(format!(
+            "{} trailing bytes after the transaction, from byte {}",
+            bytes.len() - reader.offset,
+            reader.offset
+        ));
+    }
+    if signatures.len() != header.num_required_signatures as usize {
+        return Err(format!(
+            "{} signatures, but the header requires {}",
+            signatures.len(),
+            header.num_required_signatures
+        ));
+    }
+
+    Ok(Transaction {
+        signatures,
+        version,
+        header,
+        account_keys,
+        recent_blockhash,
+        instructions,
+        address_table_lookups,
+    })
+}
+
+impl Transaction {
+    /// The account at an index, which is past the static keys for accounts loaded from lookup tables:
+    /// all writable lookups first, then all readonly ones
+    pub fn account(&self, index: u8) -> String {
+        let index = index as usize;
+        if let Some(key) = self.account_keys.get(index) {
+            return bs58::encode(key).into_string();
+        }
+
+        let writable = self
+            .address_table_lookups
+            .iter()
+            .flat_map(|lookup| lookup.writable_indexes.iter().map(move |i| (lookup, *i)));
+        let readonly = self
+            .address_table_lookups
+            .iter()
+            .flat_map(|lookup| lookup.readonly_indexes.iter().map(move |i| (lookup, *i)));
+        match writable
+            .chain(readonly)
+            .nth(index - self.account_keys.len())
+        {
+            Some((lookup, table_index)) => format!(
+                "{}[{}]",
+                bs58::encode(lookup.account_key).into_string(),
+                table_index
+            ),
+            None => format!("Missing account {}", index),
+        }
+    }
+
+    /// Signer and writable flags of a static account key, from the header
+    pub fn account_flags(&self, index: usize) -> (bool, bool) {
+        let header = &self.header;
+        let signers = header.num_required_signatures as usize;
+        let signer = index < signers;
+        let writable = if signer {
+            index < signers.saturating_sub(header.num_readonly_signed_accounts as usize)
+        } else {
+            let readonly = header.num_readonly_unsigned_accounts as usize;
+            index < self.account_keys.len().saturating_sub(readonly)
+        };
+        (signer, writable)
+    }
+}
+
+/// Decodes a serialized transaction pasted as base64 or base58
+#[derive(Default)]
+pub struct TransactionDecoder {
+    pub display_input: String,
+
+    pub display_error: Option<String>,
+    pub transaction: Option<Transaction>,
+}
+
+impl TransactionDecoder {
+    pub fn ui(&mut self, ui: &mut Ui, byte_converter: &mut BaseBytesConverter) {
+        ui.horizontal(|ui| {
+            ui.label("Transaction: ");
+            let response =
+                ui.add(TextEdit::singleline(&mut self.display_input).hint_text("Base64 or base58"));
+            if response.changed() {
+                self.decode_input();
+            }
+            if ui.button("Decode current bytes").clicked() {
+                self.display_input.clear();
+                self.decode(&byte_converter.bytes);
+            }
+        });
+
+        if let Some(error) = &self.display_error {
+            ui.colored_label(Color32::RED, error);
+        }
+        let Some(transaction) = &self.transaction else {
+            return;
+        };
+
+        let header = &transaction.header;
+        ui.monospace(format!(
+            "Version: {}, required signatures: {}, readonly signed: {}, readonly unsigned: {}",
+            transaction
+                .version
+                .map_or("legacy".to_string(), |v| v.to_string()),
+            header.num_required_signatures,
+            header.num_readonly_signed_accounts,
+            header.num_readonly_unsigned_accounts
+        ));
+        let recent_blockhash = bs58::encode(transaction.recent_blockhash).into_string();
+        ui.monospace(format!("Recent blockhash: {}", recent_blockhash));
+
+        // Every key or blob can be sent to the byte converter, to be decoded further
+        let mut send = None;
+
+        ui.label("Signatures");
+        Grid::new("transaction_signatures")
+            .striped(true)
+            .show(ui, |ui| {
+                for (index, signature) in transaction.signatures.iter().enumerate() {
+                    ui.monospace(index.to_string());
+                    ui.monospace(bs58::encode(signature).into_string());
+                    if ui.button("Send to byte converter").clicked() {
+                        send = Some(signature.to_vec());
+                    }
+                    ui.end_row();
+                }
+            });
+
+        ui.label("Account keys");
+        Grid::new("transaction_accounts")
+            .striped(true)
+            .show(ui, |ui| {
+                for (index, key) in transaction.account_keys.iter().enumerate() {
+                    let (signer, writable) = transaction.account_flags(index);
+                    ui.monospace(index.to_string());
+                    ui.monospace(bs58::encode(key).into_string());
+                    ui.monospace(if signer { "signer" } else { "" });
+                    ui.monospace(if writable { "writable" } else { "readonly" });
+                    if ui.button("Send to byte converter").clicked() {
+                        send = Some(key.to_vec());
+                    }
+                    ui.end_row();
+                }
+            });
+
+        ui.label("Instructions");
+        for (index, instruction) in transaction.instructions.iter().enumerate() {
+            ui.group(|ui| {
+                let program_id_index = instruction.program_id_index;
+                let program_name = transaction
+                    .account_keys
+                    .get(program_id_index as usize)
+                    .and_then(|key| known_id(key))
+                    .map(|known| format!(" ({})", known.name))
+                    .unwrap_or_default();
+                ui.monospace(format!(
+                    "#{} program: {}{}",
+                    index,
+                    transaction.account(program_id_index),
+                    program_name
+                ));
+                for account in &instruction.accounts {
+                    ui.monospace(format!(
+                        "  {:>3}: {}",
+                        account,
+                        transaction.account(*account)
+                    ));
+                }
+                ui.horizontal(|ui| {
+                    ui.monospace(format!(
+                        "Data ({} bytes): {}",
+                        instruction.data.len(),
+                        hex::encode(&instruction.data)
+                    ));
+                    if ui.button("Send to byte converter").clicked() {
+                        send = Some(instruction.data.clone());
+                    }
+                });
+            });
+        }
+
+        if !transaction.address_table_lookups.is_empty() {
+            ui.label("Address table lookups");
+            Grid::new("transaction_lookups")
+                .striped(true)
+                .show(ui, |ui| {
+                    for lookup in &transaction.address_table_lookups {
+                        ui.monospace(bs58::encode(lookup.account_key).into_string());
+                        ui.monospace(format!("writable {:?}", lookup.writable_indexes));
+                        ui.monospace(format!("readonly {:?}", lookup.readonly_indexes));
+                        ui.end_row();
+                    }
+                });
+        }
+
+        if let Some(bytes) = send {
+            byte_converter.update_texts(bytes);
+        }
+    }
+
+    /// Decode the input as base64, then as base58, and keep whichever is a transaction
+    fn decode_input(&mut self) {
+        let input = self.display_input.trim();
+        if input.is_empty() {
+            self.transaction = None;
+            self.display_error = None;
+            return;
+        }
+
+        let mut errors = Vec::new();
+        for (format, parse) in [
+            ("Base64", parse_base64 as fn(&str) -> _),
+            ("Base58", parse_base58),
+        ] {
+            match parse(input)
+                .map_err(|e| e.to_string())
+                .and_then(|bytes| decode_transaction(&bytes))
+            {
+                Ok(transaction) => {
+                    self.transaction = Some(transaction);
+                    self.display_error = None;
+                    return;
+                }
+                Err(e) => errors.push(format!("{}: {}", format, e)),
+            }
+        }
+        self.transaction = None;
+        self.display_error = Some(errors.join("\n"));
+    }
+
+    fn decode(&mut self, bytes: &[u8]) {
+        match decode_transaction(bytes) {
+            Ok(transaction) => {
+                self.transaction = Some(transaction);
+                self.display_error = None;
+            }
+            Err(e) => {
+                self.transaction = None;
+                self.display_error = Some(e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn compact_u16() {
+        for (bytes, expected) in [
+            (&[0x00][..], 0),
+            (&[0x7f], 0x7f),
+            (&[0x80, 0x01], 0x80),
+            (&[0xff, 0xff, 0x03], 0xffff),
+        ] {
+            let mut reader = super::Reader { bytes, offset: 0 };
+            assert_eq!(reader.compact_u16("length"), Ok(expected));
+        }
+        let mut reader = super::Reader {
+            bytes: &[0xff, 0xff, 0x04],
+            offset: 0,
+        };
+        assert!(reader.compact_u16("length").is_err());
+    }
+
+    #[test]
+    fn decode_v0_transaction() {
+        let mut bytes = vec![1];
+        bytes.extend([9; 64]);
+        bytes.extend([0x80, 1, 0, 1]);
+        bytes.push(2);
+        bytes.extend([1; 32]);
+        bytes.extend([2; 32]);
+        bytes.extend([3; 32]);
+        // Program 1 with the signer and the second lookup account
+        bytes.extend([1, 1, 2, 0, 3, 2, 0xaa, 0xbb]);
+        bytes.extend([1]);
+        bytes.extend([4; 32]);
+        bytes.extend([1, 5, 1, 6]);
+
+        let transaction = super::decode_transaction(&bytes).unwrap();
+        assert_eq!(transaction.version, Some(0));
+        assert_eq!(transaction.account_flags(0), (true, true));
+        assert_eq!(transaction.account_flags(1), (false, false));
+        let instruction = &transaction.instructions[0];
+        assert_eq!(instruction.data, [0xaa, 0xbb]);
+        assert_eq!(
+            transaction.account(instruction.accounts[1]),
+            format!("{}[6]", bs58::encode([4; 32]).into_string())
+        );
+
+        assert!(super::decode_transaction(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn decode_legacy_transaction() {
+        let mut bytes = vec![2];
+        bytes.extend([8; 64]);
+        bytes.extend([9; 64]);
+        // Two signers, the second read-only, and one read-only unsigned account
+        bytes.extend([2, 1, 1]);
+        bytes.push(5);
+        for key in 1..=5 {
+            bytes.extend([key; 32]);
+        }
+        bytes.extend([7; 32]);
+        // Program 4 with every other account
+        bytes.extend([1, 4, 4, 0, 1, 2, 3, 1, 0x01]);
+
+        let transaction = super::decode_transaction(&bytes).unwrap();
+        assert_eq!(transaction.version, None);
+        assert_eq!(transaction.signatures.len(), 2);
+        assert!(transaction.address_table_lookups.is_empty());
+        assert_eq!(transaction.recent_blockhash, [7; 32]);
+        let flags: Vec<_> = (0..5)
+            .map(|index| transaction.account_flags(index))
+            .collect();
+        assert_eq!(
+            flags,
+            [
+                (true, true),
+                (true, false),
+                (false, true),
+                (false, true),
+                (false, false)
+            ]
+        );
+        assert_eq!(transaction.instructions[0].accounts, [0, 1, 2, 3]);
+        assert_eq!(transaction.instructions[0].data, [0x01]);
+    }
+}
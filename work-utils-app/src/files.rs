@@ -0,0 +1,86 @@
+//! Reading and writing files, on both native and the web.
+//! On native, files are written to the given path, never over an existing file.
+//! On the web, they are offered as a download.
+
+/// Read the contents of a file dropped onto the window.
+/// The web build provides the bytes directly, native provides a path.
+pub fn read_dropped_file(file: &egui::DroppedFile) -> Result<Vec<u8>, String> {
+    match (&file.bytes, &file.path) {
+        (Some(bytes), _) => Ok(bytes.to_vec()),
+        (None, Some(path)) => {
+            std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+        }
+        (None, None) => Err(format!("Dropped file {} has no contents", file.name)),
+    }
+}
+
+/// Display name of a dropped file (the full path on native)
+pub fn dropped_file_name(file: &egui::DroppedFile) -> String {
+    match &file.path {
+        Some(path) => path.display().to_string(),
+        None => file.name.clone(),
+    }
+}
+
+/// Default name to save the bytes of a dropped file as: `<stem>.bin`, without the directory,
+/// so saving never targets the dropped file itself
+pub fn dropped_file_save_name(file: &egui::DroppedFile) -> String {
+    let name = dropped_file_name(file);
+    let stem = std::path::Path::new(&name)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "bytes".to_string());
+    format!("{}.bin", stem)
+}
+
+#[cfg(not(feature = "web_app"))]
+pub fn save_file(name: &str, bytes: &[u8]) -> Result<(), String> {
+    use std::io::Write;
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(name)
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::AlreadyExists => {
+                format!("{} already exists, choose another file name", name)
+            }
+            _ => format!("Failed to create {}: {}", name, e),
+        })?;
+    file.write_all(bytes)
+        .map_err(|e| format!("Failed to write {}: {}", name, e))
+}
+
+#[cfg(feature = "web_app")]
+pub fn save_file(name: &str, bytes: &[u8]) -> Result<(), String> {
+    use wasm_bindgen::JsCast;
+
+    // Offer the bytes as a download through a temporary link
+    let parts = js_sys::Array::new();
+    parts.push(&js_sys::Uint8Array::from(bytes));
+    let blob = web_sys::Blob::new_with_u8_array_sequence(&parts)
+        .map_err(|e| format!("Failed to create blob: {:?}", e))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)
+        .map_err(|e| format!("Failed to create object url: {:?}", e))?;
+
+    let window = web_sys::window().ok_or_else(|| "Failed to get window".to_string())?;
+    let anchor = window
+        .document()
+        .ok_or_else(|| "Failed to get document".to_string())?
+        .create_element("a")
+        .map_err(|e| format!("Failed to create link: {:?}", e))?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|e| format!("Failed to create link: {:?}", e))?;
+    anchor.set_href(&url);
+    anchor.set_download(name);
+    anchor.click();
+
+    // The download may start after the click returns, so the url is only revoked later.
+    // Failing to revoke just keeps the blob around, which is not worth an error.
+    let revoke = wasm_bindgen::closure::Closure::once_into_js(move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    });
+    let _ = window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(revoke.unchecked_ref(), 60_000);
+    Ok(())
+}
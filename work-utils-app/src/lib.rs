@@ -2,6 +2,7 @@ pub mod app;
 pub use app::MainApp;
 
 pub mod converter;
+pub mod files;
 
 pub const WORK_UTILS_API_URL: &str = "https://work-utils-api.wyattverchere.com/";
 
@@ -10,6 +11,10 @@ pub const WYATT_TEST_ACCOUNT: &str = "9czTJGaFdT863zno3r4t1Zd5j7pUePMguftxhm4piY
 pub const VYBE_TOKEN_ACCOUNT: &str = "vybe5DgwzGdvJMi4oH7TiQpubJd4QSDuGmbvWfACeb8";
 pub const VYBE_STAKE_VALIDATOR: &str = "6oscGUEkXE8fyWoC4czRKbM1cuLkJNtgRsX1Un6w88Vf";
 
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
 #[cfg(all(target_arch = "wasm32", feature = "web_app"))]
 mod web;
 